@@ -1,31 +1,137 @@
 pub mod naming;
-use serde::Deserialize;
+pub use crate::utils::case::CaseStyle;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 #[derive(Debug, Deserialize, Default)]
 pub struct Config {
     pub controllers_path: PathBuf,
     pub naming: NamingConfig,
     pub typescript: TypeScriptConfig,
+    pub rust: RustConfig,
+    /// Whether to cache parsed controllers in `OUT_DIR` between builds (default: true)
+    pub cache: Option<bool>,
+    /// Compare generated files against disk and fail if stale instead of writing (default: false)
+    pub check: Option<bool>,
+    /// Order of routes in generated code (default: `RouteOrder::Declaration`)
+    pub route_order: Option<RouteOrder>,
 }
 
-#[derive(Debug, Deserialize, Default)]
+/// Order of routes in generated code, e.g. `"path"` in configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RouteOrder {
+    /// Controller files in sorted order, routes in source order
+    #[default]
+    Declaration,
+    /// By path, then method
+    Path,
+    /// By route name, then path
+    Name,
+    /// By controller, keeping the declaration order within each
+    Controller,
+}
+
+impl Config {
+    /// Stable hash of every setting that affects generated code, recorded in the
+    /// `@generated` header. Settings are listed explicitly and serialized to JSON so the
+    /// hash does not depend on `Hash` implementations, and output and scan paths are
+    /// left out so it matches across machines.
+    pub fn fingerprint(&self) -> String {
+        let typescript = &self.typescript;
+        let rust = &self.rust;
+        let settings = serde_json::json!({
+            "naming": &self.naming,
+            "route_order": &self.route_order,
+            "typescript": {
+                "generate_client": typescript.generate_client,
+                "generate_types": typescript.generate_types,
+                "generate_zod_schemas": typescript.generate_zod_schemas,
+                "validate_requests": typescript.validate_requests,
+                "validate_responses": typescript.validate_responses,
+                "method_case": typescript.method_case,
+                "hook_case": typescript.hook_case,
+                "interface_case": typescript.interface_case,
+                "format": &typescript.format,
+            },
+            "rust": {
+                "layout": &rust.layout,
+                "group_by": &rust.group_by,
+                "generated_header": rust.generated_header,
+                "query_fields": rust.query_fields,
+                "controllers_module": &rust.controllers_module,
+                "base_url": &rust.base_url,
+                "http": rust.http,
+                "serde": rust.serde,
+                "extra_derives": &rust.extra_derives,
+                "template_engines": &rust.template_engines,
+                "client_types_module": &rust.client.types_module,
+                "test_links_module": &rust.test_helpers.links_module,
+                "test_types_module": &rust.test_helpers.types_module,
+            },
+        });
+        format!(
+            "{:016x}",
+            crate::utils::hash::stable_fingerprint(settings.to_string().as_str())
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
 pub struct NamingConfig {
+    /// How route names are derived (default: `NamingStrategy::Path`)
+    pub strategy: Option<NamingStrategy>,
+    /// Whether `NamingStrategy::Handler` names start with the controller module, e.g. `users_list` (default: false)
+    pub prefix_controller: Option<bool>,
     /// Whether to include HTTP methods in variant names
     pub include_method_in_names: Option<bool>,
     /// Custom prefix to remove from paths when generating names
     pub path_prefix_to_remove: Option<String>,
     /// Case for variant names (default: "PascalCase")
-    pub variant_case: Option<String>,
+    pub variant_case: Option<CaseStyle>,
     /// Case for field names (default: "snake_case")
-    pub field_case: Option<String>,
+    pub field_case: Option<CaseStyle>,
     /// Characters to treat as word separators in route names
     pub word_separators: Option<String>,
-    /// Whether to preserve numbers as separate words
+    /// Whether Rust variant and field names keep numbers in the word they are written in:
+    /// `/api/v2beta/{v2id}` gives `GetApiV2betaV2id { v2id }` instead of
+    /// `GetApiV2BetaV2Id { v_2_id }` (default: false)
     pub preserve_numbers: Option<bool>,
     /// Custom prefix for variant names
     pub variant_prefix: Option<String>,
     /// Custom suffix for variant names
     pub variant_suffix: Option<String>,
+    /// How routes with the same name are told apart. Unresolved collisions are numbered.
+    /// Default: `CollisionStrategy::Number`, with a warning
+    pub collision_strategy: Option<CollisionStrategy>,
+}
+
+/// How route names are derived, `"path"`, `"rest"` or `"handler"` in configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NamingStrategy {
+    /// Method and path, e.g. `get_users_user_id`
+    #[default]
+    Path,
+    /// Verb and resource, e.g. `get_user_by_id`, using `Path` for routes that do not read
+    /// as resources and ids
+    Rest,
+    /// The handler function, using `Path` for closures and handlers that share a name
+    Handler,
+}
+
+/// How routes with the same name are told apart, e.g. `"method"` in configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CollisionStrategy {
+    /// Fail generation, listing the colliding routes
+    Error,
+    /// Append the HTTP method, e.g. `users_get`
+    Method,
+    /// Prepend the controller, e.g. `admin_users`
+    Controller,
+    /// Append `_2`, `_3`, ... to all but the first route
+    #[default]
+    Number,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -34,4 +140,140 @@ pub struct TypeScriptConfig {
     pub output_path: Option<PathBuf>,
     /// Whether to generate TypeScript client
     pub generate_client: Option<bool>,
+    /// Whether to generate `types.ts` with serde-aware DTO definitions
+    pub generate_types: Option<bool>,
+    /// Extra files or directories to scan for DTO definitions (controllers are always scanned)
+    pub dto_paths: Option<Vec<PathBuf>>,
+    /// Whether to generate `schemas.ts` with Zod schemas from `validator` rules
+    pub generate_zod_schemas: Option<bool>,
+    /// Whether the generated client validates request bodies before sending (default: false)
+    pub validate_requests: Option<bool>,
+    /// Whether the generated client validates responses against their schemas (default: false)
+    pub validate_responses: Option<bool>,
+    /// Case for client method names (default: "camelCase")
+    pub method_case: Option<CaseStyle>,
+    /// Case for hook names, which start with `use` (default: "camelCase")
+    pub hook_case: Option<CaseStyle>,
+    /// Case for path parameter interface names, which end in `Params` (default: "PascalCase")
+    pub interface_case: Option<CaseStyle>,
+    /// Formatting of the generated TypeScript files
+    pub format: TypeScriptFormatConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct TypeScriptFormatConfig {
+    /// Spaces per indentation level (default: 2)
+    pub indent_width: Option<u8>,
+    /// Whether to indent with tabs instead of spaces (default: false)
+    pub use_tabs: Option<bool>,
+    /// Quote style for strings (default: `QuoteStyle::Double`)
+    pub quote_style: Option<QuoteStyle>,
+    /// Whether to terminate statements with semicolons (default: true)
+    pub semicolons: Option<bool>,
+    /// Maximum line width (default: 80)
+    pub line_width: Option<u32>,
+}
+
+/// Quotes around generated TypeScript strings, `"double"` or `"single"` in configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteStyle {
+    #[default]
+    Double,
+    Single,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct RustConfig {
+    /// Where to write the `Link` code: a `.rs` file for the "single" layout,
+    /// a module directory for "per_controller"
+    pub output_path: Option<PathBuf>,
+    /// Write to `OUT_DIR` instead, as `links.rs` or `links/mod.rs`, for use with `include!`
+    pub use_out_dir: Option<bool>,
+    /// Module layout (default: `RustLayout::Single`)
+    pub layout: Option<RustLayout>,
+    /// Nest variants in one enum per group, as in
+    /// `Link::Users(UsersLink::Get { user_id })` (default: one flat enum)
+    pub group_by: Option<GroupBy>,
+    /// Whether to start generated files with an `@generated` header (default: true)
+    pub generated_header: Option<bool>,
+    /// Whether variants of handlers taking `Query<T>` get a `query: Option<T>` field (default: false).
+    /// `T` must implement `Serialize`, `Debug`, `Clone` and `PartialEq`, and the crate must
+    /// depend on `serde` and `serde_urlencoded`
+    pub query_fields: Option<bool>,
+    /// Module containing the controllers, used to name query types (default: "crate::controllers")
+    pub controllers_module: Option<String>,
+    /// Base URL for the generated `Link::to_url`, e.g. "https://example.com"
+    pub base_url: Option<String>,
+    /// Whether `Link::method` returns `http::Method` and `Link` converts to `http` types
+    /// (default: false). The crate must depend on `http`
+    pub http: Option<bool>,
+    /// Whether `Link` derives `Serialize`/`Deserialize` as `{"route": "GetUser", "params": {..}}`
+    /// and gets a `JSON_SCHEMA` of that form (default: false). The crate must depend on `serde`
+    pub serde: Option<bool>,
+    /// Extra derives on `Link`, e.g. `["PartialOrd", "schemars::JsonSchema"]`
+    pub extra_derives: Option<Vec<String>>,
+    /// Template engines to generate `link` helpers for. The crate must depend on the listed
+    /// engines
+    pub template_engines: Option<Vec<TemplateEngine>>,
+    /// Typed `reqwest` client for calling the API from Rust
+    pub client: RustClientConfig,
+    /// Typed request helpers for integration tests
+    pub test_helpers: RustTestHelpersConfig,
+}
+
+/// How `Link` variants are nested with `rust.group_by`, `"controller"` or `"path_prefix"` in
+/// configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+    /// One group per controller file
+    Controller,
+    /// One group per first path segment after the prefix shared by all routes
+    PathPrefix,
+}
+
+/// Template engine with a generated `link` helper, `"tera"`, `"minijinja"` or `"askama"` in
+/// configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateEngine {
+    /// `register_tera_link`, registering a `link` function on a `tera::Tera`
+    Tera,
+    /// `register_minijinja_link`, registering a `link` function on a `minijinja::Environment`
+    Minijinja,
+    /// A plain `link` function to call from Askama templates
+    Askama,
+}
+
+/// How the `Link` code is split into files, `"single"` or `"per_controller"` in configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RustLayout {
+    /// One `.rs` file
+    #[default]
+    Single,
+    /// A module directory: `mod.rs` with the `Link` enum and a module of path
+    /// builders per controller
+    PerController,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct RustClientConfig {
+    /// Where to write the client; no client is generated when unset
+    pub output_path: Option<PathBuf>,
+    /// Module the client imports request and response types from, e.g. "my_api::dto"
+    /// (default: the controller module the handler is declared in)
+    pub types_module: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct RustTestHelpersConfig {
+    /// Where to write the `TestLinks` trait; nothing is generated when unset
+    pub output_path: Option<PathBuf>,
+    /// Path of the generated `Link` enum as seen from the tests (default: "crate::links")
+    pub links_module: Option<String>,
+    /// Module the helpers import request and response types from, e.g. "my_app::views"
+    /// (default: the controller module the handler is declared in)
+    pub types_module: Option<String>,
 }
@@ -1,4 +1,29 @@
-pub fn generate_route_name(path: &str, method: &str, config: &super::NamingConfig) -> String {
+use super::{CollisionStrategy, NamingStrategy};
+use crate::RouteInfo;
+use std::collections::{HashMap, HashSet};
+
+/// Name of a parsed route, once its handler and controller are known
+pub fn route_name(
+    route: &RouteInfo,
+    config: &super::NamingConfig,
+) -> Result<String, Box<dyn std::error::Error>> {
+    if config.strategy == Some(NamingStrategy::Handler) && !route.handler.is_empty() {
+        let name = if config.prefix_controller.unwrap_or(false) && !route.controller.is_empty() {
+            format!("{}_{}", route.controller, route.handler)
+        } else {
+            route.handler.clone()
+        };
+        return Ok(crate::utils::case::sanitize_identifier(&name));
+    }
+    generate_route_name(&route.path, &route.method, config)
+}
+
+/// Name derived from the path and method; `NamingStrategy::Handler` names fall back to this
+pub fn generate_route_name(
+    path: &str,
+    method: &str,
+    config: &super::NamingConfig,
+) -> Result<String, Box<dyn std::error::Error>> {
     let include_method = config.include_method_in_names.unwrap_or(true);
 
     let mut name_path = path.to_string();
@@ -21,6 +46,12 @@ pub fn generate_route_name(path: &str, method: &str, config: &super::NamingConfi
         }
     }
 
+    if config.strategy == Some(NamingStrategy::Rest)
+        && let Some(name) = rest_route_name(&name_path, method, config)
+    {
+        return Ok(crate::utils::case::sanitize_identifier(&name));
+    }
+
     // Clean the path for name generation (this only affects the name, not the actual URL)
     let clean_path = clean_route_path_for_name(&name_path, config);
 
@@ -37,7 +68,114 @@ pub fn generate_route_name(path: &str, method: &str, config: &super::NamingConfi
     };
 
     // Apply final transformations to ensure valid identifier
-    crate::utils::case::sanitize_identifier(&name)
+    Ok(crate::utils::case::sanitize_identifier(&name))
+}
+
+/// Verb and resource name for paths made of resources and their ids: `GET /users` →
+/// `list_users`, `GET /users/{id}` → `get_user_by_id`, `POST /posts/{post_id}/comments`
+/// → `create_post_comment`. Leading segments such as `api/v1` are left out.
+///
+/// `None` for anything else, e.g. actions like `POST /auth/login` or `GET /users/me`.
+fn rest_route_name(path: &str, method: &str, config: &super::NamingConfig) -> Option<String> {
+    let is_param = |segment: &str| segment.starts_with('{');
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    // Resources in path order, singular when addressed by an id
+    let mut resources: Vec<(String, Option<String>)> = Vec::new();
+    let mut index = 0;
+    while index < segments.len() {
+        let segment = segments[index];
+        if is_param(segment) {
+            return None;
+        }
+        let word = clean_route_path_for_name(segment, config);
+        match segments.get(index + 1) {
+            Some(next) if is_param(next) => {
+                let param = clean_route_path_for_name(&next.replace('*', ""), config);
+                resources.push((singularize(&word), Some(param)));
+                index += 2;
+                continue;
+            }
+            // Namespaces before the first resource
+            Some(_) if resources.is_empty() => {}
+            // Actions and sub-paths below a resource
+            Some(_) => return None,
+            None => resources.push((word, None)),
+        }
+        index += 1;
+    }
+
+    let ((resource, param), parents) = resources.split_last()?;
+    let is_collection = singularize(resource) != *resource;
+    let mut words: Vec<String> = parents.iter().map(|(parent, _)| parent.clone()).collect();
+    let verb = match (param, method) {
+        (Some(param), "GET" | "PUT" | "PATCH" | "DELETE") => {
+            // `user_id` of `users` is just the `id`
+            let param = param
+                .strip_prefix(resource.as_str())
+                .and_then(|rest| rest.strip_prefix('_'))
+                .filter(|rest| !rest.is_empty())
+                .unwrap_or(param);
+            words.extend([resource.clone(), "by".to_string(), param.to_string()]);
+            match method {
+                "GET" => "get",
+                "DELETE" => "delete",
+                _ => "update",
+            }
+        }
+        // Only plural segments are collections; `/auth/login` is an action
+        (None, "GET") if is_collection => {
+            words.push(resource.clone());
+            "list"
+        }
+        (None, "POST") if is_collection => {
+            words.push(singularize(resource));
+            "create"
+        }
+        _ => return None,
+    };
+
+    Some(format!("{}_{}", verb, words.join("_")))
+}
+
+/// English singular of the last word of a snake_case name, e.g. `blog_categories` →
+/// `blog_category`; words that don't look plural are returned unchanged
+fn singularize(name: &str) -> String {
+    let (head, word) = match name.rsplit_once('_') {
+        Some((head, word)) => (format!("{}_", head), word),
+        None => (String::new(), name),
+    };
+    let irregular = [
+        ("people", "person"),
+        ("children", "child"),
+        ("men", "man"),
+        ("women", "woman"),
+        ("mice", "mouse"),
+    ];
+    let singular = if let Some((_, singular)) = irregular.iter().find(|(plural, _)| *plural == word)
+    {
+        singular.to_string()
+    } else if let Some(stem) = word.strip_suffix("ies").filter(|stem| stem.len() > 1) {
+        format!("{}y", stem)
+    } else if ["sses", "shes", "ches", "xes", "zes"]
+        .iter()
+        .any(|suffix| word.ends_with(suffix))
+    {
+        word[..word.len() - 2].to_string()
+    } else if word.len() > 1
+        && word.ends_with('s')
+        && !["ss", "us", "is"]
+            .iter()
+            .any(|suffix| word.ends_with(suffix))
+    {
+        word[..word.len() - 1].to_string()
+    } else {
+        word.to_string()
+    };
+    format!("{}{}", head, singular)
 }
 
 fn clean_route_path_for_name(path: &str, config: &super::NamingConfig) -> String {
@@ -63,3 +201,116 @@ fn clean_route_path_for_name(path: &str, config: &super::NamingConfig) -> String
 
     result.trim_matches('_').to_string()
 }
+
+/// Make route names unique with `naming.collision_strategy`, keeping the first
+/// route of a collision unchanged when numbering.
+///
+/// Names are compared ignoring case and separators, as they end up in PascalCase,
+/// camelCase and snake_case identifiers alike.
+pub fn resolve_name_collisions(
+    entries: &mut [(String, &RouteInfo)],
+    config: &super::NamingConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut collisions = collision_sets(entries);
+
+    // Handlers sharing a name, like `list` in every controller, are told apart by their paths
+    if config.strategy == Some(NamingStrategy::Handler) && !collisions.is_empty() {
+        for &index in collisions.iter().flatten() {
+            let (name, route) = &mut entries[index];
+            if !route.handler.is_empty() {
+                *name = generate_route_name(&route.path, &route.method, config)?;
+            }
+        }
+        collisions = collision_sets(entries);
+    }
+
+    for set in &collisions {
+        let routes = set
+            .iter()
+            .map(|&index| format!("{} {}", entries[index].1.method, entries[index].1.path))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let name = &entries[set[0]].0;
+        match config.collision_strategy {
+            Some(CollisionStrategy::Error) => {
+                return Err(format!(
+                    "Routes {} share the name '{}'; set naming.collision_strategy to \"method\", \"controller\" or \"number\" to tell them apart",
+                    routes, name
+                )
+                .into());
+            }
+            Some(CollisionStrategy::Method) => {
+                for &index in set {
+                    let (name, route) = &mut entries[index];
+                    *name = format!("{}_{}", name, route.method.to_lowercase());
+                }
+            }
+            Some(CollisionStrategy::Controller) => {
+                for &index in set {
+                    let (name, route) = &mut entries[index];
+                    if !route.controller.is_empty() {
+                        *name = format!("{}_{}", route.controller, name);
+                    }
+                }
+            }
+            None => {
+                println!(
+                    "cargo:warning=Routes {} share the name '{}' and are numbered; set naming.collision_strategy to choose how they are told apart",
+                    routes, name
+                );
+            }
+            Some(CollisionStrategy::Number) => {}
+        }
+    }
+
+    // Number whatever still collides, skipping names other routes already have
+    let reserved: HashSet<String> = entries
+        .iter()
+        .map(|(name, _)| collision_key(name))
+        .collect();
+    let mut used = HashSet::new();
+    for (name, _) in entries.iter_mut() {
+        if used.insert(collision_key(name)) {
+            continue;
+        }
+        let mut number = 2;
+        let numbered = loop {
+            let candidate = format!("{}_{}", name, number);
+            let key = collision_key(&candidate);
+            if !used.contains(&key) && !reserved.contains(&key) {
+                used.insert(key);
+                break candidate;
+            }
+            number += 1;
+        };
+        *name = numbered;
+    }
+
+    Ok(())
+}
+
+/// Indices of the entries sharing each colliding name, in order of first use
+fn collision_sets(entries: &[(String, &RouteInfo)]) -> Vec<Vec<usize>> {
+    let mut sets: Vec<Vec<usize>> = Vec::new();
+    let mut first_index: HashMap<String, usize> = HashMap::new();
+    for (index, (name, _)) in entries.iter().enumerate() {
+        let key = collision_key(name);
+        match first_index.get(&key) {
+            Some(&set) => sets[set].push(index),
+            None => {
+                first_index.insert(key, sets.len());
+                sets.push(vec![index]);
+            }
+        }
+    }
+    sets.retain(|set| set.len() > 1);
+    sets
+}
+
+/// Name with case and separators removed
+fn collision_key(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
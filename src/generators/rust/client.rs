@@ -0,0 +1,486 @@
+use super::{
+    LinkVariant, builder_name, collect_variants, encoding, format_rust_code, generated_header, http,
+};
+use crate::{
+    RouteInfo,
+    config::{Config, RustConfig},
+    generators::CodeGenerator,
+    utils::path::{PathSegment, parse_path_template},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::path::PathBuf;
+
+/// Async `reqwest` client with one method per route.
+///
+/// The generated code needs `reqwest` (with the `json` and, from 0.13, `query`
+/// features), `serde` and `serde_json`.
+pub struct RustClientGenerator;
+
+impl CodeGenerator for RustClientGenerator {
+    type Config = Config;
+    type Output = String;
+
+    fn generate(
+        routes: &[RouteInfo],
+        config: &Self::Config,
+    ) -> Result<Self::Output, Box<dyn std::error::Error>> {
+        let variants = collect_variants(routes, config)?;
+        let types_module = config.rust.client.types_module.as_deref();
+
+        let mut methods = Vec::new();
+        let mut error_enums = Vec::new();
+        let mut needs_encoding = false;
+        let mut has_wildcards = false;
+
+        for variant in &variants {
+            let route = variant.route;
+            let path_types = path_param_types(variant);
+            let segments = parse_path_template(&route.path);
+            has_wildcards |= segments
+                .iter()
+                .any(|segment| matches!(segment, PathSegment::Wildcard(_)));
+            needs_encoding |= path_types.iter().any(Option::is_none);
+
+            // Path parameters first, then the body, then the query
+            let mut arguments: Vec<TokenStream> = variant
+                .fields
+                .iter()
+                .zip(&path_types)
+                .map(|(field, path_type)| match path_type {
+                    Some(path_type) => quote! { #field: #path_type },
+                    None => quote! { #field: &str },
+                })
+                .collect();
+            let body = route
+                .handler_info
+                .body_param
+                .as_deref()
+                .map(|body| resolve_type(body, route, &config.rust, types_module));
+            if let Some(body_type) = &body {
+                arguments.push(quote! { body: &#body_type });
+            }
+            let query = route
+                .handler_info
+                .query_params
+                .as_deref()
+                .map(|query| resolve_type(query, route, &config.rust, types_module));
+            if let Some(query_type) = &query {
+                arguments.push(quote! { query: Option<&#query_type> });
+            }
+
+            let return_type = match route.handler_info.return_type.found_type.as_deref() {
+                Some(found_type) => resolve_type(found_type, route, &config.rust, types_module),
+                None => quote! { serde_json::Value },
+            };
+
+            let error_type = match generate_error_enum(variant) {
+                Some((error_ident, error_enum)) => {
+                    error_enums.push(error_enum);
+                    quote! { #error_ident }
+                }
+                None => quote! { std::convert::Infallible },
+            };
+
+            let url = generate_url_code(variant, &path_types);
+            let method = http::method_constant(&route.method, &quote! { reqwest::Method });
+            let body_call = body.as_ref().map(|_| {
+                quote! { let request = request.json(body); }
+            });
+            let query_call = query.as_ref().map(|_| {
+                quote! {
+                    let request = match query {
+                        Some(query) => request.query(query),
+                        None => request,
+                    };
+                }
+            });
+            let requires_auth = route.handler_info.requires_auth;
+            let fn_name = builder_name(variant);
+            let doc = format!(" `{} {}`", route.method, route.path);
+
+            methods.push(quote! {
+                #[doc = #doc]
+                pub async fn #fn_name(&self, #(#arguments),*) -> Result<#return_type, ClientError<#error_type>> {
+                    let request = self.http.request(#method, #url);
+                    #body_call
+                    #query_call
+                    self.send(request, #requires_auth).await
+                }
+            });
+        }
+
+        let encode_helpers = if needs_encoding || has_wildcards {
+            encoding::generate_encode_helpers(has_wildcards)
+        } else {
+            quote! {}
+        };
+        let support = generate_support_types();
+
+        let generated = quote! {
+            #support
+
+            /// Typed client for every application route
+            #[derive(Debug, Clone)]
+            pub struct ApiClient {
+                http: reqwest::Client,
+                base_url: String,
+                token: Option<String>,
+            }
+
+            impl ApiClient {
+                /// Client for the API served at `base_url`, e.g. `https://example.com`
+                pub fn new(base_url: impl Into<String>) -> Self {
+                    Self::with_client(reqwest::Client::new(), base_url)
+                }
+
+                /// Client reusing an existing `reqwest::Client`
+                pub fn with_client(http: reqwest::Client, base_url: impl Into<String>) -> Self {
+                    let base_url = base_url.into().trim_end_matches('/').to_string();
+                    Self {
+                        http,
+                        base_url,
+                        token: None,
+                    }
+                }
+
+                /// Bearer token sent to routes that require authentication
+                pub fn with_token(mut self, token: impl Into<String>) -> Self {
+                    self.token = Some(token.into());
+                    self
+                }
+
+                /// Replace or clear the bearer token
+                pub fn set_token(&mut self, token: Option<String>) {
+                    self.token = token;
+                }
+
+                async fn send<T, E>(
+                    &self,
+                    request: reqwest::RequestBuilder,
+                    requires_auth: bool,
+                ) -> Result<T, ClientError<E>>
+                where
+                    T: serde::de::DeserializeOwned,
+                    E: RouteError,
+                {
+                    let request = match &self.token {
+                        Some(token) if requires_auth => request.bearer_auth(token),
+                        _ => request,
+                    };
+                    let response = request.send().await?;
+                    let status = response.status();
+                    let bytes = response.bytes().await?;
+
+                    if !status.is_success() {
+                        let error = serde_json::from_slice::<RawApiError>(&bytes).ok();
+                        let route_error = error
+                            .as_ref()
+                            .and_then(|error| error.details())
+                            .and_then(|details| E::from_code(&details.code));
+                        return Err(match route_error {
+                            Some(error) => ClientError::Route { status, error },
+                            None => ClientError::Api { status, error },
+                        });
+                    }
+
+                    // Handlers answering with `format::empty()` send no body at all
+                    let body: &[u8] = if bytes.is_empty() { b"null" } else { &bytes };
+                    serde_json::from_slice(body).map_err(ClientError::Decode)
+                }
+
+                #(#methods)*
+            }
+
+            #(#error_enums)*
+
+            #encode_helpers
+        };
+
+        format_rust_code(generated)
+    }
+}
+
+impl RustClientGenerator {
+    /// The client file with its `@generated` header, or none when
+    /// `rust.client.output_path` is unset
+    pub fn generate_files(
+        routes: &[RouteInfo],
+        config: &Config,
+    ) -> Result<Vec<(PathBuf, String)>, Box<dyn std::error::Error>> {
+        let Some(output_path) = &config.rust.client.output_path else {
+            return Ok(Vec::new());
+        };
+        let code = Self::generate(routes, config)?;
+        Ok(vec![(
+            output_path.clone(),
+            format!("{}{}", generated_header(config), code),
+        )])
+    }
+}
+
+/// Error types shared by every client method
+fn generate_support_types() -> TokenStream {
+    quote! {
+        /// Error body sent by the server
+        #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+        pub struct RawApiError {
+            pub error: String,
+            #[serde(default)]
+            pub description: String,
+        }
+
+        /// Structured details carried in the description of Bad Request errors
+        #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+        pub struct ErrorDetails {
+            pub code: String,
+            pub message: String,
+        }
+
+        impl RawApiError {
+            /// Details parsed from `description`, if it holds any
+            pub fn details(&self) -> Option<ErrorDetails> {
+                serde_json::from_str(&self.description).ok()
+            }
+        }
+
+        /// Errors a route is known to return, recognised by their error code
+        pub trait RouteError: Sized {
+            fn from_code(code: &str) -> Option<Self>;
+        }
+
+        impl RouteError for std::convert::Infallible {
+            fn from_code(_code: &str) -> Option<Self> {
+                None
+            }
+        }
+
+        /// Failure of a client call
+        #[derive(Debug)]
+        pub enum ClientError<E = std::convert::Infallible> {
+            /// The request could not be sent or the response could not be read
+            Transport(reqwest::Error),
+            /// The response body did not match the expected type
+            Decode(serde_json::Error),
+            /// One of the errors the route is known to return
+            Route { status: reqwest::StatusCode, error: E },
+            /// Any other error response
+            Api {
+                status: reqwest::StatusCode,
+                error: Option<RawApiError>,
+            },
+        }
+
+        impl<E> From<reqwest::Error> for ClientError<E> {
+            fn from(error: reqwest::Error) -> Self {
+                ClientError::Transport(error)
+            }
+        }
+
+        impl<E: std::fmt::Debug> std::fmt::Display for ClientError<E> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    ClientError::Transport(error) => write!(f, "request failed: {error}"),
+                    ClientError::Decode(error) => write!(f, "invalid response body: {error}"),
+                    ClientError::Route { status, error } => write!(f, "{status}: {error:?}"),
+                    ClientError::Api {
+                        status,
+                        error: Some(error),
+                    } => write!(f, "{status}: {} {}", error.error, error.description),
+                    ClientError::Api { status, error: None } => write!(f, "{status}"),
+                }
+            }
+        }
+
+        impl<E: std::fmt::Debug> std::error::Error for ClientError<E> {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match self {
+                    ClientError::Transport(error) => Some(error),
+                    ClientError::Decode(error) => Some(error),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// `{Variant}Error` enum of the error variants a handler returns, matched against
+/// the `code` of Bad Request details regardless of case and separators
+fn generate_error_enum(variant: &LinkVariant) -> Option<(proc_macro2::Ident, TokenStream)> {
+    let mut names: Vec<&str> = Vec::new();
+    for error_variant in &variant.route.handler_info.return_type.error_variants {
+        let name = error_variant.rsplit("::").next().unwrap_or(error_variant);
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    if names.is_empty() {
+        return None;
+    }
+
+    let error_ident = format_ident!("{}Error", variant.flat_name());
+    let doc = format!(
+        " Errors returned by `{} {}`",
+        variant.route.method, variant.route.path
+    );
+    let idents: Vec<proc_macro2::Ident> = names
+        .iter()
+        .map(|name| format_ident!("{}", crate::utils::case::sanitize_identifier(name)))
+        .collect();
+    let codes = names.iter().map(|name| normalize_code(name));
+
+    let error_enum = quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum #error_ident {
+            #(#idents),*
+        }
+
+        impl RouteError for #error_ident {
+            fn from_code(code: &str) -> Option<Self> {
+                let code: String = code
+                    .chars()
+                    .filter(|c| c.is_ascii_alphanumeric())
+                    .map(|c| c.to_ascii_lowercase())
+                    .collect();
+                match code.as_str() {
+                    #(#codes => Some(#error_ident::#idents),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+    Some((error_ident, error_enum))
+}
+
+/// Error code reduced to lowercase alphanumerics, so `UserNotFound`,
+/// `user_not_found` and `USER-NOT-FOUND` all match
+fn normalize_code(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Rust type of each path parameter when `Path<T>` names a primitive; `None`
+/// means the parameter is passed as `&str` and percent-encoded
+pub fn path_param_types(variant: &LinkVariant) -> Vec<Option<TokenStream>> {
+    let declared = &variant.route.handler_info.path_params;
+    let segments = parse_path_template(&variant.route.path);
+
+    variant
+        .params
+        .iter()
+        .enumerate()
+        .map(|(index, param)| {
+            let is_wildcard = segments
+                .iter()
+                .any(|segment| matches!(segment, PathSegment::Wildcard(name) if name == param));
+            if is_wildcard || declared.len() != variant.params.len() {
+                return None;
+            }
+            match declared[index].as_str() {
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+                | "u128" | "usize" | "bool" => {
+                    let ident = format_ident!("{}", declared[index]);
+                    Some(quote! { #ident })
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// `format!` building the absolute URL of a route
+fn generate_url_code(variant: &LinkVariant, path_types: &[Option<TokenStream>]) -> TokenStream {
+    let mut format_string = String::from("{}");
+    let mut arguments = vec![quote! { self.base_url }];
+
+    for segment in parse_path_template(&variant.route.path) {
+        format_string.push('/');
+        match &segment {
+            PathSegment::Static(segment) => {
+                format_string.push_str(&segment.replace('{', "{{").replace('}', "}}"));
+            }
+            PathSegment::Param(name) | PathSegment::Wildcard(name) => {
+                let index = variant
+                    .params
+                    .iter()
+                    .position(|param| param == name)
+                    .expect("path parameters are collected from the template");
+                let field = &variant.fields[index];
+                format_string.push_str("{}");
+                let is_wildcard = matches!(segment, PathSegment::Wildcard(_));
+                arguments.push(match (&path_types[index], is_wildcard) {
+                    (Some(_), _) => quote! { #field },
+                    (None, true) => quote! { encode_path_segments(#field) },
+                    (None, false) => quote! { encode_path_segment(#field) },
+                });
+            }
+        }
+    }
+
+    quote! { format!(#format_string, #(#arguments),*) }
+}
+
+/// Rust type for a type name recorded from a handler. DTOs are looked up in
+/// `types_module`, or else in the handler's controller module.
+pub fn resolve_type(
+    type_name: &str,
+    route: &RouteInfo,
+    config: &RustConfig,
+    types_module: Option<&str>,
+) -> TokenStream {
+    if let Some(inner) = type_name
+        .strip_prefix("Array<")
+        .and_then(|rest| rest.strip_suffix('>'))
+    {
+        let inner = resolve_type(inner, route, config, types_module);
+        return quote! { Vec<#inner> };
+    }
+
+    let is_builtin = matches!(
+        type_name,
+        "i8" | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+            | "f32"
+            | "f64"
+            | "bool"
+            | "char"
+            | "String"
+    );
+    let full_path = if is_builtin || type_name.contains("::") {
+        type_name.to_string()
+    } else if let Some(types_module) = types_module {
+        format!("{}::{}", types_module, type_name)
+    } else {
+        let controllers_module = config
+            .controllers_module
+            .as_deref()
+            .unwrap_or("crate::controllers");
+        format!(
+            "{}::{}::{}",
+            controllers_module, route.controller, type_name
+        )
+    };
+
+    match syn::parse_str::<syn::Type>(&full_path) {
+        Ok(ty) => quote! { #ty },
+        Err(_) => {
+            println!(
+                "cargo:warning=Using serde_json::Value for {} {}: '{}' is not a type",
+                route.method, route.path, full_path
+            );
+            quote! { serde_json::Value }
+        }
+    }
+}
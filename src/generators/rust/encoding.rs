@@ -0,0 +1,146 @@
+use super::LinkVariant;
+use crate::utils::path::{PathSegment, parse_path_template};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Percent-encoding helpers emitted next to `Link`, only those the routes need.
+/// Paths are written straight into a `fmt::Write`, so encoding never allocates.
+///
+/// Encoding keeps the same characters as JavaScript's `encodeURIComponent`, so the
+/// Rust and TypeScript generators build identical URLs.
+pub fn generate_encoding_helpers(variants: &[LinkVariant]) -> TokenStream {
+    let has_params = variants.iter().any(|variant| !variant.fields.is_empty());
+    let has_wildcards = variants.iter().any(|variant| {
+        parse_path_template(&variant.route.path)
+            .iter()
+            .any(|segment| matches!(segment, PathSegment::Wildcard(_)))
+    });
+
+    if !has_params {
+        return quote! {};
+    }
+
+    let wildcard_helpers = if has_wildcards {
+        quote! {
+            /// Write each segment of a catch-all value percent-encoded, keeping the `/` separators
+            fn write_path_segments<W: std::fmt::Write + ?Sized>(
+                w: &mut W,
+                value: &str,
+            ) -> std::fmt::Result {
+                for (index, segment) in value.split('/').enumerate() {
+                    if index > 0 {
+                        w.write_char('/')?;
+                    }
+                    write_path_segment(w, segment)?;
+                }
+                Ok(())
+            }
+
+            /// Decode the segments matched by a catch-all parameter
+            fn decode_path_segments(segments: &[&str]) -> Option<String> {
+                let decoded = segments
+                    .iter()
+                    .map(|segment| decode_path_segment(segment))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(decoded.join("/"))
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        /// Write a path segment percent-encoded like JavaScript's `encodeURIComponent`
+        fn write_path_segment<W: std::fmt::Write + ?Sized>(w: &mut W, value: &str) -> std::fmt::Result {
+            fn is_unreserved(byte: u8) -> bool {
+                byte.is_ascii_alphanumeric() || b"-_.!~*'()".contains(&byte)
+            }
+
+            const HEX: &[u8; 16] = b"0123456789ABCDEF";
+            let bytes = value.as_bytes();
+            let mut start = 0;
+            while start < bytes.len() {
+                // Runs of unreserved ASCII are written as is, so slicing stays on char boundaries
+                let end = bytes[start..]
+                    .iter()
+                    .position(|&byte| !is_unreserved(byte))
+                    .map_or(bytes.len(), |offset| start + offset);
+                w.write_str(&value[start..end])?;
+
+                start = end;
+                while start < bytes.len() && !is_unreserved(bytes[start]) {
+                    let byte = bytes[start];
+                    w.write_char('%')?;
+                    w.write_char(HEX[(byte >> 4) as usize] as char)?;
+                    w.write_char(HEX[(byte & 0x0f) as usize] as char)?;
+                    start += 1;
+                }
+            }
+            Ok(())
+        }
+
+        /// Decode a percent-encoded path segment; `None` if it is malformed or not UTF-8
+        fn decode_path_segment(segment: &str) -> Option<String> {
+            let mut decoded = Vec::with_capacity(segment.len());
+            let mut bytes = segment.bytes();
+            while let Some(byte) = bytes.next() {
+                if byte == b'%' {
+                    let high = (bytes.next()? as char).to_digit(16)?;
+                    let low = (bytes.next()? as char).to_digit(16)?;
+                    decoded.push((high * 16 + low) as u8);
+                } else {
+                    decoded.push(byte);
+                }
+            }
+            String::from_utf8(decoded).ok()
+        }
+
+        #wildcard_helpers
+    }
+}
+
+/// `encode_path_segment`, plus `encode_path_segments` for catch-all parameters
+pub fn generate_encode_helpers(wildcards: bool) -> TokenStream {
+    let wildcard_helper = if wildcards {
+        quote! {
+            /// Percent-encode each segment of a catch-all value, keeping the `/` separators
+            fn encode_path_segments(value: &str) -> String {
+                value
+                    .split('/')
+                    .map(encode_path_segment)
+                    .collect::<Vec<_>>()
+                    .join("/")
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        /// Percent-encode a path segment like JavaScript's `encodeURIComponent`
+        fn encode_path_segment(value: &str) -> std::borrow::Cow<'_, str> {
+            fn is_unreserved(byte: u8) -> bool {
+                byte.is_ascii_alphanumeric() || b"-_.!~*'()".contains(&byte)
+            }
+
+            if value.bytes().all(is_unreserved) {
+                return std::borrow::Cow::Borrowed(value);
+            }
+
+            const HEX: &[u8; 16] = b"0123456789ABCDEF";
+            let mut encoded = String::with_capacity(value.len() * 3);
+            for byte in value.bytes() {
+                if is_unreserved(byte) {
+                    encoded.push(byte as char);
+                } else {
+                    encoded.push('%');
+                    encoded.push(HEX[(byte >> 4) as usize] as char);
+                    encoded.push(HEX[(byte & 0x0f) as usize] as char);
+                }
+            }
+            std::borrow::Cow::Owned(encoded)
+        }
+
+        #wildcard_helper
+    }
+}
@@ -0,0 +1,70 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Value returned by `Link::method` for a route: an `http::Method` constant when the
+/// `http` integration is enabled, the method name otherwise
+pub fn method_value(method: &str, http: bool) -> TokenStream {
+    if !http {
+        return quote! { #method };
+    }
+    method_constant(method, &quote! { http::Method })
+}
+
+/// `Method` constant of the given method type, e.g. `http::Method::GET`
+pub fn method_constant(method: &str, method_type: &TokenStream) -> TokenStream {
+    match method {
+        "GET" | "POST" | "PUT" | "PATCH" | "DELETE" | "HEAD" | "OPTIONS" | "TRACE" | "CONNECT" => {
+            let constant = format_ident!("{}", method);
+            quote! { #method_type::#constant }
+        }
+        other => {
+            let bytes = syn::LitByteStr::new(other.as_bytes(), proc_macro2::Span::call_site());
+            quote! { #method_type::from_bytes(#bytes).expect("valid HTTP method") }
+        }
+    }
+}
+
+/// Return type of `Link::method`
+pub fn method_type(http: bool) -> TokenStream {
+    if http {
+        quote! { http::Method }
+    } else {
+        quote! { &'static str }
+    }
+}
+
+/// `to_uri` and `request_builder` on `Link`
+pub fn generate_http_methods() -> TokenStream {
+    quote! {
+        /// Path and query as an `http::Uri`
+        pub fn to_uri(&self) -> http::Uri {
+            self.to_path_and_query()
+                .parse()
+                .expect("generated paths are valid URIs")
+        }
+
+        /// Request builder with this link's method and URI already set
+        pub fn request_builder(&self) -> http::request::Builder {
+            http::Request::builder()
+                .method(self.method())
+                .uri(self.to_uri())
+        }
+    }
+}
+
+/// Conversions from `Link` into `http` types
+pub fn generate_http_impls() -> TokenStream {
+    quote! {
+        impl From<Link> for http::Uri {
+            fn from(link: Link) -> Self {
+                link.to_uri()
+            }
+        }
+
+        impl From<&Link> for http::Uri {
+            fn from(link: &Link) -> Self {
+                link.to_uri()
+            }
+        }
+    }
+}
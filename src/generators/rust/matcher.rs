@@ -0,0 +1,192 @@
+use super::LinkVariant;
+use crate::utils::path::{PathSegment, parse_path_template};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap};
+
+/// Segment trie of all routes, compiled into the body of `Link::from_path`
+#[derive(Default)]
+struct RouteNode<'a> {
+    statics: BTreeMap<String, RouteNode<'a>>,
+    param: Option<Box<RouteNode<'a>>>,
+    /// Routes whose template ends at this node
+    routes: Vec<Endpoint<'a>>,
+    /// Routes whose catch-all parameter starts at this node
+    wildcard_routes: Vec<Endpoint<'a>>,
+}
+
+/// A route reached in the trie, with the expression producing each of its fields
+struct Endpoint<'a> {
+    variant: &'a LinkVariant<'a>,
+    values: Vec<TokenStream>,
+}
+
+/// Generate `Link::from_path` and `Link::match_request`.
+///
+/// Matching walks the path one segment at a time and prefers static segments over
+/// parameters over catch-alls, backtracking when a branch has no matching template,
+/// the same precedence Axum's router applies.
+pub fn generate_from_path(variants: &[LinkVariant]) -> TokenStream {
+    let mut root = RouteNode::default();
+    for variant in variants {
+        root.insert(variant);
+    }
+    let matcher = root.render(0);
+
+    quote! {
+        /// Parse a concrete path such as `/users/42` back into its link
+        pub fn from_path(method: &str, path: &str) -> Option<Link> {
+            let path = path.strip_prefix('/')?;
+            let segments: Vec<&str> = if path.is_empty() {
+                Vec::new()
+            } else {
+                path.split('/').collect()
+            };
+            let rest = segments.as_slice();
+            #matcher
+            None
+        }
+
+        /// Match a request target such as `/users/42?tab=posts`, ignoring the query and fragment
+        pub fn match_request(method: &str, target: &str) -> Option<Link> {
+            let path = target.split(['?', '#']).next().unwrap_or(target);
+            Self::from_path(method, path)
+        }
+    }
+}
+
+impl<'a> RouteNode<'a> {
+    fn insert(&mut self, variant: &'a LinkVariant<'a>) {
+        let mut node = self;
+        let mut bindings: HashMap<String, TokenStream> = HashMap::new();
+
+        for (depth, segment) in parse_path_template(&variant.route.path)
+            .into_iter()
+            .enumerate()
+        {
+            match segment {
+                PathSegment::Static(segment) => {
+                    node = node.statics.entry(segment).or_default();
+                }
+                PathSegment::Param(name) => {
+                    let binding = format_ident!("p{}", depth);
+                    bindings
+                        .entry(name)
+                        .or_insert_with(|| quote! { decode_path_segment(#binding)? });
+                    node = node.param.get_or_insert_with(Default::default);
+                }
+                // A catch-all consumes the rest of the path
+                PathSegment::Wildcard(name) => {
+                    bindings.entry(name).or_insert_with(|| quote! { wildcard });
+                    let endpoint = Endpoint::new(variant, &bindings);
+                    node.wildcard_routes.push(endpoint);
+                    return;
+                }
+            }
+        }
+
+        let endpoint = Endpoint::new(variant, &bindings);
+        node.routes.push(endpoint);
+    }
+
+    fn render(&self, depth: usize) -> TokenStream {
+        // Once the path matches a template, a method mismatch does not fall back
+        // to other templates, just like Axum answering 405
+        let terminal = if self.routes.is_empty() {
+            quote! {}
+        } else {
+            let endpoints = render_endpoints(&self.routes);
+            quote! {
+                if rest.is_empty() {
+                    #endpoints
+                    return None;
+                }
+            }
+        };
+
+        let statics = self.statics.iter().map(|(segment, child)| {
+            let child = child.render(depth + 1);
+            quote! {
+                if let Some((&#segment, tail)) = rest.split_first() {
+                    let rest = tail;
+                    #child
+                }
+            }
+        });
+
+        // Parameters never match an empty segment, e.g. the end of `/users/`
+        let param = self.param.as_ref().map(|child| {
+            let binding = format_ident!("p{}", depth);
+            let child = child.render(depth + 1);
+            quote! {
+                if let Some((&#binding, tail)) = rest
+                    .split_first()
+                    .filter(|(segment, _)| !segment.is_empty())
+                {
+                    let rest = tail;
+                    #child
+                }
+            }
+        });
+
+        let wildcard = if self.wildcard_routes.is_empty() {
+            quote! {}
+        } else {
+            let endpoints = render_endpoints(&self.wildcard_routes);
+            quote! {
+                if !rest.is_empty() {
+                    let wildcard = decode_path_segments(rest)?;
+                    #endpoints
+                    return None;
+                }
+            }
+        };
+
+        quote! {
+            #terminal
+            #(#statics)*
+            #param
+            #wildcard
+        }
+    }
+}
+
+impl<'a> Endpoint<'a> {
+    fn new(variant: &'a LinkVariant<'a>, bindings: &HashMap<String, TokenStream>) -> Self {
+        let values = variant
+            .params
+            .iter()
+            .map(|param| bindings[param].clone())
+            .collect();
+        Self { variant, values }
+    }
+}
+
+/// Return the route registered for the request method, if any
+fn render_endpoints(endpoints: &[Endpoint]) -> TokenStream {
+    let checks = endpoints.iter().map(|endpoint| {
+        let route_method = &endpoint.variant.route.method;
+        let link = if endpoint.variant.is_unit() {
+            endpoint.variant.link_path(&quote! {}, None)
+        } else {
+            let fields = &endpoint.variant.fields;
+            let values = &endpoint.values;
+            // Paths carry no query string; `match_request` does not parse one either
+            let query = endpoint
+                .variant
+                .query
+                .as_ref()
+                .map(|_| quote! { query: None });
+            endpoint
+                .variant
+                .link_path(&quote! {}, Some(quote! { #(#fields: #values,)* #query }))
+        };
+        quote! {
+            if method == #route_method {
+                return Some(#link);
+            }
+        }
+    });
+
+    quote! { #(#checks)* }
+}
@@ -0,0 +1,85 @@
+use super::LinkVariant;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// `RouteMeta` and the static `ROUTES` table, one entry per `Link` variant in order
+pub fn generate_route_table(variants: &[LinkVariant]) -> TokenStream {
+    let entries = variants.iter().map(|variant| {
+        let name = variant.qualified_name();
+        let method = &variant.route.method;
+        let template = &variant.route.path;
+        let requires_auth = variant.route.handler_info.requires_auth;
+        let handler = &variant.route.handler;
+        let controller = &variant.route.controller;
+        quote! {
+            RouteMeta {
+                name: #name,
+                method: #method,
+                template: #template,
+                requires_auth: #requires_auth,
+                handler: #handler,
+                controller: #controller,
+            }
+        }
+    });
+
+    quote! {
+        /// Static description of a route, available without rescanning sources
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct RouteMeta {
+            /// `Link` variant name, e.g. `GetUser`, or `Users::Get` with groups
+            pub name: &'static str,
+            pub method: &'static str,
+            /// Path pattern with `{param}` placeholders
+            pub template: &'static str,
+            pub requires_auth: bool,
+            /// Handler function name
+            pub handler: &'static str,
+            /// Controller module the route is declared in
+            pub controller: &'static str,
+        }
+
+        /// Every route of the application, in the order of the `Link` variants
+        pub static ROUTES: &[RouteMeta] = &[#(#entries),*];
+    }
+}
+
+/// Accessors on `Link` backed by `Link::meta`
+pub fn generate_meta_methods(variants: &[LinkVariant]) -> TokenStream {
+    let mut templates: Vec<&str> = Vec::new();
+    for variant in variants {
+        if !templates.contains(&variant.route.path.as_str()) {
+            templates.push(&variant.route.path);
+        }
+    }
+
+    quote! {
+        /// Every distinct path template, in generated route order (see `route_order`)
+        pub const ALL_TEMPLATES: &'static [&'static str] = &[#(#templates),*];
+
+        /// Variant name, e.g. `GetUser`, or `Users::Get` with groups
+        pub fn name(&self) -> &'static str {
+            self.meta().name
+        }
+
+        /// Path pattern with `{param}` placeholders
+        pub fn template(&self) -> &'static str {
+            self.meta().template
+        }
+
+        /// Whether the handler requires authentication
+        pub fn requires_auth(&self) -> bool {
+            self.meta().requires_auth
+        }
+
+        /// Handler function name
+        pub fn handler(&self) -> &'static str {
+            self.meta().handler
+        }
+
+        /// Controller module the route is declared in
+        pub fn controller(&self) -> &'static str {
+            self.meta().controller
+        }
+    }
+}
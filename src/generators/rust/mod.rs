@@ -0,0 +1,955 @@
+mod client;
+mod encoding;
+mod http;
+mod matcher;
+mod meta;
+mod schema;
+mod templates;
+mod test_links;
+mod url;
+
+pub use client::RustClientGenerator;
+pub use test_links::RustTestLinksGenerator;
+
+use super::CodeGenerator;
+use crate::{
+    RouteInfo,
+    config::{Config, GroupBy, NamingConfig, NamingStrategy, RustConfig, RustLayout},
+    utils::{
+        case::CaseStyle,
+        path::{PathSegment, parse_path_template},
+    },
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+pub struct RustLinksGenerator;
+
+impl CodeGenerator for RustLinksGenerator {
+    type Config = Config;
+    type Output = String;
+
+    fn generate(
+        routes: &[RouteInfo],
+        config: &Self::Config,
+    ) -> Result<Self::Output, Box<dyn std::error::Error>> {
+        let variants = collect_variants(routes, config)?;
+        let write_path_bodies = variants
+            .iter()
+            .map(|variant| write_path_code(variant, &quote! {}, true))
+            .collect();
+        let generated = render_link_enum(&variants, write_path_bodies, config)?;
+
+        format_rust_code(generated)
+    }
+}
+
+impl RustLinksGenerator {
+    /// Files to write for the configured `rust` output and layout, or none when
+    /// no output location is configured
+    pub fn generate_files(
+        routes: &[RouteInfo],
+        config: &Config,
+    ) -> Result<Vec<(PathBuf, String)>, Box<dyn std::error::Error>> {
+        let per_controller = config.rust.layout.unwrap_or_default() == RustLayout::PerController;
+
+        let use_out_dir = config.rust.use_out_dir.unwrap_or(false);
+        let output_path = if use_out_dir {
+            let out_dir = std::env::var_os("OUT_DIR").ok_or(
+                "rust.use_out_dir requires running from a build script (OUT_DIR is not set)",
+            )?;
+            let file_name = if per_controller { "links" } else { "links.rs" };
+            PathBuf::from(out_dir).join(file_name)
+        } else {
+            match &config.rust.output_path {
+                Some(output_path) => output_path.clone(),
+                None => return Ok(Vec::new()),
+            }
+        };
+
+        let header = generated_header(config);
+        if !per_controller {
+            let code = Self::generate(routes, config)?;
+            return Ok(vec![(output_path, format!("{header}{code}"))]);
+        }
+
+        let variants = collect_variants(routes, config)?;
+        let mut controllers: BTreeMap<String, Vec<&LinkVariant>> = BTreeMap::new();
+        for variant in &variants {
+            controllers
+                .entry(controller_module_name(&variant.route.controller))
+                .or_default()
+                .push(variant);
+        }
+
+        let mut files = Vec::new();
+        let mut module_declarations = Vec::new();
+        for (module_name, module_variants) in &controllers {
+            let module_path = output_path.join(format!("{module_name}.rs"));
+            let module_ident = syn::Ident::new(module_name, proc_macro2::Span::call_site());
+
+            // `include!`d files resolve `mod` paths relative to the includer, so
+            // modules in OUT_DIR are referenced by absolute path
+            if use_out_dir {
+                let absolute = module_path.display().to_string();
+                module_declarations.push(quote! {
+                    #[path = #absolute]
+                    pub mod #module_ident;
+                });
+            } else {
+                module_declarations.push(quote! { pub mod #module_ident; });
+            }
+
+            let builders = module_variants.iter().map(|variant| {
+                let fn_name = builder_name(variant);
+                let write_fn_name = quote::format_ident!("write_{}", fn_name);
+                let doc = format!(" Path of `{} {}`", variant.route.method, variant.route.path);
+                let write_doc = format!(
+                    " Write the path of `{} {}` into `w`",
+                    variant.route.method, variant.route.path
+                );
+                let params = &variant.fields;
+                let body = write_path_code(variant, &quote! { super:: }, false);
+                quote! {
+                    #[doc = #write_doc]
+                    pub fn #write_fn_name<W: std::fmt::Write + ?Sized>(
+                        w: &mut W,
+                        #(#params: &str),*
+                    ) -> std::fmt::Result {
+                        #body
+                    }
+
+                    #[doc = #doc]
+                    pub fn #fn_name(#(#params: &str),*) -> String {
+                        // `w` is reserved, no parameter may take it
+                        let mut w = String::new();
+                        #write_fn_name(&mut w, #(#params),*)
+                            .expect("writing to a String cannot fail");
+                        w
+                    }
+                }
+            });
+            let module_code = quote! { #(#builders)* };
+            files.push((
+                module_path,
+                format!("{header}{}", format_rust_code(module_code)?),
+            ));
+        }
+
+        // `Link` stays in `mod.rs` and delegates path building to the controller modules
+        let write_path_bodies = variants
+            .iter()
+            .map(|variant| {
+                let module_ident = syn::Ident::new(
+                    &controller_module_name(&variant.route.controller),
+                    proc_macro2::Span::call_site(),
+                );
+                let write_fn_name = quote::format_ident!("write_{}", builder_name(variant));
+                let fields = &variant.fields;
+                quote! { #module_ident::#write_fn_name(w, #(#fields),*) }
+            })
+            .collect();
+        let link_enum = render_link_enum(&variants, write_path_bodies, config)?;
+        let mod_code = quote! {
+            #(#module_declarations)*
+            #link_enum
+        };
+        files.insert(
+            0,
+            (
+                output_path.join("mod.rs"),
+                format!("{header}{}", format_rust_code(mod_code)?),
+            ),
+        );
+
+        Ok(files)
+    }
+
+    /// The `Link` code of the "single" layout file from [`Self::generate_files`] without
+    /// its header, or `None` for other layouts and when nothing is written
+    pub fn single_file_code(files: &[(PathBuf, String)], config: &Config) -> Option<String> {
+        if config.rust.layout.unwrap_or_default() != RustLayout::Single {
+            return None;
+        }
+        let (_, content) = files.first()?;
+        content
+            .strip_prefix(&generated_header(config))
+            .map(str::to_string)
+    }
+}
+
+/// A route that made it into the `Link` enum
+struct LinkVariant<'a> {
+    route: &'a RouteInfo,
+    /// Variant name, unique within its group
+    ident: proc_macro2::Ident,
+    /// `Link` variant wrapping the nested enum of the group, with `rust.group_by`
+    group: Option<proc_macro2::Ident>,
+    /// Path parameter names as written in the route template
+    params: Vec<String>,
+    /// Field for each entry of `params`
+    fields: Vec<proc_macro2::Ident>,
+    /// Type of the optional `query` field, for handlers taking `Query<T>`
+    query: Option<syn::Path>,
+}
+
+impl LinkVariant<'_> {
+    /// Whether the variant has no fields at all
+    fn is_unit(&self) -> bool {
+        self.fields.is_empty() && self.query.is_none()
+    }
+
+    /// Name unique across groups, for generated items named after the variant
+    fn flat_name(&self) -> String {
+        match &self.group {
+            Some(group) => format!("{}{}", group, self.ident),
+            None => self.ident.to_string(),
+        }
+    }
+
+    /// Name returned by `Link::name`, e.g. `GetUser` or `Users::Get` when grouped
+    fn qualified_name(&self) -> String {
+        match &self.group {
+            Some(group) => format!("{}::{}", group, self.ident),
+            None => self.ident.to_string(),
+        }
+    }
+
+    /// The variant as a `Link` pattern or constructor, with `fields` in braces when
+    /// given, e.g. `Link::Users(UsersLink::Get { .. })`; `module` prefixes the enums
+    fn link_path(&self, module: &TokenStream, fields: Option<TokenStream>) -> TokenStream {
+        let variant_name = &self.ident;
+        let variant = match fields {
+            Some(fields) => quote! { #variant_name { #fields } },
+            None => quote! { #variant_name },
+        };
+        match &self.group {
+            Some(group) => {
+                let group_enum = group_enum_name(group);
+                quote! { #module Link::#group(#module #group_enum::#variant) }
+            }
+            None => quote! { #module Link::#variant },
+        }
+    }
+}
+
+/// A route with its `rust.group_by` group and the name its variant is derived from
+type GroupedRoute<'a> = (&'a RouteInfo, Option<proc_macro2::Ident>, String);
+
+/// A variant at its index in `ROUTES`, with its `write_path` arm body
+type IndexedVariant<'a> = (usize, &'a LinkVariant<'a>, TokenStream);
+
+/// Nested enum holding the variants of a group
+fn group_enum_name(group: &proc_macro2::Ident) -> proc_macro2::Ident {
+    quote::format_ident!("{}Link", group)
+}
+
+/// Names the generated code gives its own fields, arguments and locals next to the
+/// path parameters: the `query` field, the `body` and `token` arguments of the client
+/// and test helpers, and the `w` writer of `write_path`
+const RESERVED_FIELD_NAMES: &[&str] = &["query", "body", "token", "w"];
+
+fn collect_variants<'a>(
+    routes: &'a [RouteInfo],
+    config: &Config,
+) -> Result<Vec<LinkVariant<'a>>, Box<dyn std::error::Error>> {
+    let naming = &config.naming;
+    let mut variants = Vec::new();
+
+    // Route names are unique by now, but case conversion and sanitizing could still merge two
+    let mut unique_variants: HashMap<String, &RouteInfo> = HashMap::new();
+
+    for (route, group, name) in group_routes(routes, config)? {
+        let variant_name = create_variant_name(&name, naming);
+        let variant_name_str = match &group {
+            Some(group) => format!("{}::{}", group, variant_name),
+            None => variant_name.to_string(),
+        };
+
+        if let Some(existing_route) = unique_variants.get(&variant_name_str) {
+            return Err(format!(
+                "Duplicate variant name '{}' for routes: {} {} and {} {}",
+                variant_name_str,
+                route.method,
+                route.path,
+                existing_route.method,
+                existing_route.path
+            )
+            .into());
+        }
+
+        unique_variants.insert(variant_name_str, route);
+
+        // Extract parameters from path (e.g., {id})
+        let params = crate::utils::path::extract_parameters_from_path(&route.path);
+        let mut fields = Vec::with_capacity(params.len());
+        for param in &params {
+            let field_name = create_field_name(param, naming);
+            if RESERVED_FIELD_NAMES.contains(&field_name.as_str()) {
+                return Err(format!(
+                    "Path parameter '{}' of {} {} becomes the field `{}`, which the generated \
+                     code already uses; rename the parameter (reserved: {})",
+                    param,
+                    route.method,
+                    route.path,
+                    field_name,
+                    RESERVED_FIELD_NAMES.join(", ")
+                )
+                .into());
+            }
+            fields.push(syn::Ident::new(&field_name, proc_macro2::Span::call_site()));
+        }
+
+        let query = url::query_type(route, &config.rust);
+
+        variants.push(LinkVariant {
+            route,
+            ident: variant_name,
+            group,
+            params,
+            fields,
+            query,
+        });
+    }
+
+    Ok(variants)
+}
+
+/// Routes with their group and the name their variant is derived from, in `Link` order.
+///
+/// Without `rust.group_by` every route keeps its name. Otherwise routes are
+/// grouped by controller or by the first path segment after the prefix all routes
+/// share, groups keep the order of their first route, and names are built from
+/// the path below the static prefix shared within the group: `GET /api/users`
+/// and `GET /api/users/{id}` become `Users::Get` and `Users::GetId`.
+/// `NamingStrategy::Handler` uses handler names as they are.
+fn group_routes<'a>(
+    routes: &'a [RouteInfo],
+    config: &Config,
+) -> Result<Vec<GroupedRoute<'a>>, Box<dyn std::error::Error>> {
+    let by_controller = match config.rust.group_by {
+        None => {
+            return Ok(routes
+                .iter()
+                .map(|route| (route, None, route.name.clone()))
+                .collect());
+        }
+        Some(GroupBy::Controller) => true,
+        Some(GroupBy::PathPrefix) => false,
+    };
+
+    let segments: Vec<Vec<&str>> = routes
+        .iter()
+        .map(|route| {
+            route
+                .path
+                .split('/')
+                .filter(|segment| !segment.is_empty())
+                .collect()
+        })
+        .collect();
+    let shared = common_static_prefix(&segments.iter().collect::<Vec<_>>());
+
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    for (index, route) in routes.iter().enumerate() {
+        let key = if by_controller {
+            route.controller.clone()
+        } else {
+            match segments[index].get(shared) {
+                Some(segment) if !segment.starts_with('{') => segment.to_string(),
+                _ => String::new(),
+            }
+        };
+        match groups.iter_mut().find(|(group, _)| *group == key) {
+            Some((_, members)) => members.push(index),
+            None => groups.push((key, vec![index])),
+        }
+    }
+
+    let include_method = config.naming.include_method_in_names.unwrap_or(true);
+    let by_handler = config.naming.strategy == Some(NamingStrategy::Handler);
+    let mut grouped = Vec::new();
+    for (key, members) in groups {
+        let group_name = convert_name(&key, CaseStyle::Pascal, &config.naming);
+        let group_name = if group_name.is_empty() {
+            "Root".to_string()
+        } else {
+            crate::utils::case::sanitize_identifier(&group_name)
+        };
+        let group = syn::Ident::new(&group_name, proc_macro2::Span::call_site());
+
+        let prefix = common_static_prefix(
+            &members
+                .iter()
+                .map(|&index| &segments[index])
+                .collect::<Vec<_>>(),
+        );
+        let mut names: Vec<(String, &RouteInfo)> = Vec::new();
+        for index in members {
+            let route = &routes[index];
+            let relative = segments[index][prefix..].join("/");
+            let name = if by_handler && !route.handler.is_empty() {
+                // The group already says which controller or resource the handler is for
+                crate::utils::case::sanitize_identifier(&route.handler)
+            } else if relative.is_empty() && include_method {
+                route.method.to_lowercase()
+            } else {
+                crate::config::naming::generate_route_name(
+                    &relative,
+                    &route.method,
+                    &config.naming,
+                )?
+            };
+            names.push((name, route));
+        }
+        // Shorter names can collide within a group, e.g. `/api/users` and `/api/users/`
+        crate::config::naming::resolve_name_collisions(&mut names, &config.naming)?;
+        grouped.extend(
+            names
+                .into_iter()
+                .map(|(name, route)| (route, Some(group.clone()), name)),
+        );
+    }
+
+    Ok(grouped)
+}
+
+/// Number of leading static segments all paths have in common
+fn common_static_prefix(paths: &[&Vec<&str>]) -> usize {
+    let Some((first, rest)) = paths.split_first() else {
+        return 0;
+    };
+    let mut length = first
+        .iter()
+        .take_while(|segment| !segment.starts_with('{'))
+        .count();
+    for path in rest {
+        length = first[..length]
+            .iter()
+            .zip(path.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+    }
+    length
+}
+
+/// Declarations and path, method and metadata accessors of an enum of route variants
+struct RouteEnum {
+    declarations: Vec<TokenStream>,
+    /// `to_path` and `write_path`
+    path_methods: TokenStream,
+    method: TokenStream,
+    meta: TokenStream,
+}
+
+/// Render the variants of `Link`, or of a nested group enum, at their indices in `ROUTES`
+fn render_route_enum(
+    enum_name: &proc_macro2::Ident,
+    variants: &[IndexedVariant],
+    config: &RustConfig,
+) -> RouteEnum {
+    let use_http = config.http.unwrap_or(false);
+    let use_serde = config.serde.unwrap_or(false);
+    let mut declarations = Vec::new();
+    let mut write_arms = Vec::new();
+    let mut static_path_arms = Vec::new();
+    let mut method_arms = Vec::new();
+    let mut meta_arms = Vec::new();
+
+    for (index, variant, write_path_body) in variants {
+        let variant_name = &variant.ident;
+        let route_method = http::method_value(&variant.route.method, use_http);
+        let fields = &variant.fields;
+
+        if variant.is_unit() {
+            // No parameters variant
+            declarations.push(quote! {
+                #variant_name
+            });
+
+            write_arms.push(quote! {
+                #enum_name::#variant_name => #write_path_body
+            });
+        } else {
+            // With parameters variant
+            let query_declaration = variant.query.as_ref().map(|query_type| {
+                let serde_attribute = use_serde.then(|| {
+                    quote! { #[serde(default, skip_serializing_if = "Option::is_none")] }
+                });
+                quote! {
+                    /// Query string parameters
+                    #serde_attribute
+                    query: Option<#query_type>
+                }
+            });
+            let field_declarations = fields
+                .iter()
+                .map(|field| quote! { #field: String })
+                .chain(query_declaration);
+            declarations.push(quote! {
+                #variant_name {
+                    #(#field_declarations),*
+                }
+            });
+
+            let rest = variant.query.as_ref().map(|_| quote! { .. });
+            write_arms.push(quote! {
+                #enum_name::#variant_name { #(#fields,)* #rest } => #write_path_body
+            });
+        }
+
+        let wildcard_pattern = if variant.is_unit() {
+            quote! { #enum_name::#variant_name }
+        } else {
+            quote! { #enum_name::#variant_name { .. } }
+        };
+        if fields.is_empty() {
+            let route_path = &variant.route.path;
+            static_path_arms.push(quote! {
+                #wildcard_pattern => std::borrow::Cow::Borrowed(#route_path)
+            });
+        }
+        method_arms.push(quote! {
+            #wildcard_pattern => #route_method
+        });
+        meta_arms.push(quote! {
+            #wildcard_pattern => &ROUTES[#index]
+        });
+    }
+
+    // Only paths with parameters are built at runtime
+    let owned_path = quote! {
+        let mut path = String::with_capacity(self.meta().template.len());
+        self.write_path(&mut path)
+            .expect("writing to a String cannot fail");
+        std::borrow::Cow::Owned(path)
+    };
+    let to_path_body = if static_path_arms.is_empty() {
+        owned_path
+    } else {
+        let owned_path_arm =
+            (static_path_arms.len() < variants.len()).then(|| quote! { _ => { #owned_path } });
+        quote! {
+            match self {
+                #(#static_path_arms,)*
+                #owned_path_arm
+            }
+        }
+    };
+
+    let method_type = http::method_type(use_http);
+    RouteEnum {
+        declarations,
+        path_methods: quote! {
+            /// Convert the link to a URL path; borrowed for routes without path parameters
+            pub fn to_path(&self) -> std::borrow::Cow<'static, str> {
+                #to_path_body
+            }
+
+            /// Write the URL path into `w` without intermediate allocations
+            pub fn write_path<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+                match self {
+                    #(#write_arms),*
+                }
+            }
+        },
+        method: quote! {
+            /// Get the HTTP method for this route
+            pub fn method(&self) -> #method_type {
+                match self {
+                    #(#method_arms),*
+                }
+            }
+        },
+        meta: quote! {
+            /// Static metadata of this link's route
+            pub fn meta(&self) -> &'static RouteMeta {
+                match self {
+                    #(#meta_arms),*
+                }
+            }
+        },
+    }
+}
+
+/// `Link` with one variant per group delegating to the nested group enums
+fn render_grouped_enums(
+    variants: &[LinkVariant],
+    write_path_bodies: Vec<TokenStream>,
+    derives: &[syn::Path],
+    config: &RustConfig,
+) -> (RouteEnum, TokenStream) {
+    let use_http = config.http.unwrap_or(false);
+    let serde_attribute = config
+        .serde
+        .unwrap_or(false)
+        .then(|| quote! { #[serde(tag = "route", content = "params")] });
+
+    let mut groups: Vec<(&proc_macro2::Ident, Vec<IndexedVariant>)> = Vec::new();
+    for (index, (variant, body)) in variants.iter().zip(write_path_bodies).enumerate() {
+        let group = variant
+            .group
+            .as_ref()
+            .expect("grouped variants have a group");
+        match groups.iter_mut().find(|(name, _)| *name == group) {
+            Some((_, members)) => members.push((index, variant, body)),
+            None => groups.push((group, vec![(index, variant, body)])),
+        }
+    }
+
+    let mut declarations = Vec::new();
+    let mut nested = Vec::new();
+    for (group, members) in &groups {
+        let group_enum = group_enum_name(group);
+        let doc = format!(" Routes of the `{}` group", group);
+        declarations.push(quote! {
+            #[doc = #doc]
+            #group(#group_enum)
+        });
+
+        let RouteEnum {
+            declarations: variant_declarations,
+            path_methods,
+            method,
+            meta,
+        } = render_route_enum(&group_enum, members, config);
+        let enum_doc = format!(" Links to the routes of the `{}` group", group);
+        nested.push(quote! {
+            #[doc = #enum_doc]
+            #[derive(#(#derives),*)]
+            #serde_attribute
+            pub enum #group_enum {
+                #(#variant_declarations),*
+            }
+
+            impl #group_enum {
+                #path_methods
+
+                #method
+
+                #meta
+            }
+
+            impl From<#group_enum> for Link {
+                fn from(link: #group_enum) -> Self {
+                    Link::#group(link)
+                }
+            }
+
+            impl std::fmt::Display for #group_enum {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    self.write_path(f)
+                }
+            }
+        });
+    }
+
+    let group_names: Vec<_> = groups.iter().map(|(group, _)| *group).collect();
+    let method_type = http::method_type(use_http);
+    let link = RouteEnum {
+        declarations,
+        path_methods: quote! {
+            /// Convert the link to a URL path; borrowed for routes without path parameters
+            pub fn to_path(&self) -> std::borrow::Cow<'static, str> {
+                match self {
+                    #(Link::#group_names(link) => link.to_path()),*
+                }
+            }
+
+            /// Write the URL path into `w` without intermediate allocations
+            pub fn write_path<W: std::fmt::Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+                match self {
+                    #(Link::#group_names(link) => link.write_path(w)),*
+                }
+            }
+        },
+        method: quote! {
+            /// Get the HTTP method for this route
+            pub fn method(&self) -> #method_type {
+                match self {
+                    #(Link::#group_names(link) => link.method()),*
+                }
+            }
+        },
+        meta: quote! {
+            /// Static metadata of this link's route
+            pub fn meta(&self) -> &'static RouteMeta {
+                match self {
+                    #(Link::#group_names(link) => link.meta()),*
+                }
+            }
+        },
+    };
+
+    (link, quote! { #(#nested)* })
+}
+
+/// The `Link` enum with its `write_path` arms given per variant
+fn render_link_enum(
+    variants: &[LinkVariant],
+    write_path_bodies: Vec<TokenStream>,
+    config: &Config,
+) -> Result<TokenStream, Box<dyn std::error::Error>> {
+    let use_http = config.rust.http.unwrap_or(false);
+    let use_serde = config.rust.serde.unwrap_or(false);
+    let grouped = config.rust.group_by.is_some();
+    let derives = link_derives(variants, &config.rust)?;
+
+    let (link, nested_enums) = if grouped {
+        render_grouped_enums(variants, write_path_bodies, &derives, &config.rust)
+    } else {
+        let indexed: Vec<_> = variants
+            .iter()
+            .zip(write_path_bodies)
+            .enumerate()
+            .map(|(index, (variant, body))| (index, variant, body))
+            .collect();
+        let link_ident = syn::Ident::new("Link", proc_macro2::Span::call_site());
+        (
+            render_route_enum(&link_ident, &indexed, &config.rust),
+            quote! {},
+        )
+    };
+    let RouteEnum {
+        declarations,
+        path_methods,
+        method,
+        meta,
+    } = link;
+
+    let from_path = matcher::generate_from_path(variants);
+    let meta_methods = meta::generate_meta_methods(variants);
+    let route_table = meta::generate_route_table(variants);
+    let url_methods = url::generate_url_methods(variants, &config.rust);
+    let (http_methods, http_impls) = if use_http {
+        (http::generate_http_methods(), http::generate_http_impls())
+    } else {
+        (quote! {}, quote! {})
+    };
+    let encoding_helpers = encoding::generate_encoding_helpers(variants);
+    let (from_name, template_helpers) = templates::generate_template_helpers(
+        variants,
+        config.rust.template_engines.as_deref().unwrap_or(&[]),
+    );
+    let (serde_attribute, json_schema) = if use_serde {
+        let json_schema = schema::generate_json_schema(variants);
+        let serde_attribute = if grouped {
+            quote! { #[serde(tag = "group", content = "link")] }
+        } else {
+            quote! { #[serde(tag = "route", content = "params")] }
+        };
+        let doc = if grouped {
+            " JSON Schema of the serialized form, `{\"group\": \"Users\", \"link\": {\"route\": \"Get\", ..}}`"
+        } else {
+            " JSON Schema of the serialized form, `{\"route\": \"GetUser\", \"params\": {..}}`"
+        };
+        (
+            serde_attribute,
+            quote! {
+                #[doc = #doc]
+                pub const JSON_SCHEMA: &'static str = #json_schema;
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    Ok(quote! {
+        /// Auto-generated link enum for all application routes
+        #[derive(#(#derives),*)]
+        #serde_attribute
+        pub enum Link {
+            #(#declarations),*
+        }
+
+        impl Link {
+            #path_methods
+
+            #url_methods
+
+            #method
+
+            #http_methods
+
+            #meta
+
+            #meta_methods
+
+            #from_path
+
+            #from_name
+
+            #json_schema
+        }
+
+        impl std::fmt::Display for Link {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.write_path(f)
+            }
+        }
+
+        #nested_enums
+
+        #http_impls
+
+        #route_table
+
+        #template_helpers
+
+        #encoding_helpers
+    })
+}
+
+/// Derives on `Link`: `Eq` and `Hash` unless a query type is involved, serde
+/// derives when enabled, then `rust.extra_derives`
+fn link_derives(
+    variants: &[LinkVariant],
+    config: &RustConfig,
+) -> Result<Vec<syn::Path>, Box<dyn std::error::Error>> {
+    let mut names = vec!["Debug", "Clone", "PartialEq"];
+    // Query types are user code and need not implement `Eq` or `Hash`
+    if variants.iter().all(|variant| variant.query.is_none()) {
+        names.extend(["Eq", "Hash"]);
+    }
+    if config.serde.unwrap_or(false) {
+        names.extend(["serde::Serialize", "serde::Deserialize"]);
+    }
+    for extra in config.extra_derives.iter().flatten() {
+        if !names.contains(&extra.as_str()) {
+            names.push(extra);
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            syn::parse_str(name).map_err(|e| {
+                format!("Invalid derive '{}' in rust.extra_derives: {}", name, e).into()
+            })
+        })
+        .collect()
+}
+
+/// Pretty-print generated tokens like rustfmt would, keeping doc comments and item order
+pub fn format_rust_code(tokens: TokenStream) -> Result<String, Box<dyn std::error::Error>> {
+    let file: syn::File =
+        syn::parse2(tokens).map_err(|e| format!("Generated Rust code failed to parse: {}", e))?;
+    Ok(prettyplease::unparse(&file))
+}
+
+/// `// @generated` marker recording the crate version and config fingerprint
+fn generated_header(config: &Config) -> String {
+    if !config.rust.generated_header.unwrap_or(true) {
+        return String::new();
+    }
+    format!(
+        "// @generated by route-info-builder {} (config {}). Do not edit by hand.\n\n",
+        env!("CARGO_PKG_VERSION"),
+        config.fingerprint()
+    )
+}
+
+fn controller_module_name(controller: &str) -> String {
+    let name = crate::utils::case::convert_to_case(controller, CaseStyle::Snake);
+    if name.is_empty() || name == "mod" {
+        return "routes".to_string();
+    }
+    crate::utils::case::sanitize_identifier(&name)
+}
+
+/// Name of the per-controller path builder function for a variant
+fn builder_name(variant: &LinkVariant) -> proc_macro2::Ident {
+    let name = crate::utils::case::convert_to_case(&variant.flat_name(), CaseStyle::Snake);
+    syn::Ident::new(
+        &crate::utils::case::sanitize_identifier(&name),
+        proc_macro2::Span::call_site(),
+    )
+}
+
+fn create_variant_name(name: &str, config: &NamingConfig) -> proc_macro2::Ident {
+    let case = config.variant_case.unwrap_or(CaseStyle::Pascal);
+    let mut result = convert_name(name, case, config);
+
+    // Apply prefix and suffix
+    if let Some(prefix) = &config.variant_prefix {
+        result = format!("{}{}", prefix, result);
+    }
+
+    if let Some(suffix) = &config.variant_suffix {
+        result = format!("{}{}", result, suffix);
+    }
+
+    // Ensure it's a valid identifier
+    let sanitized = crate::utils::case::sanitize_identifier(&result);
+    syn::Ident::new(&sanitized, proc_macro2::Span::call_site())
+}
+
+fn create_field_name(name: &str, config: &NamingConfig) -> String {
+    let case = config.field_case.unwrap_or(CaseStyle::Snake);
+    let result = convert_name(name, case, config);
+    crate::utils::case::sanitize_identifier(&result)
+}
+
+/// `name` in `case`, keeping numbers in their words with `naming.preserve_numbers`
+fn convert_name(name: &str, case: CaseStyle, config: &NamingConfig) -> String {
+    if config.preserve_numbers.unwrap_or(false) {
+        crate::utils::case::convert_to_case_preserving_numbers(name, case)
+    } else {
+        crate::utils::case::convert_to_case(name, case)
+    }
+}
+
+/// Statements writing the path of a variant into `w` and evaluating to `fmt::Result`,
+/// wrapped in a block when `braced` and there is more than one; `helpers` is the
+/// module path of the encoding helpers relative to where they are used
+fn write_path_code(variant: &LinkVariant, helpers: &TokenStream, braced: bool) -> TokenStream {
+    let route_path = &variant.route.path;
+    if variant.fields.is_empty() {
+        return quote! { w.write_str(#route_path) };
+    }
+
+    // Consecutive static segments are written as one literal
+    let mut steps = Vec::new();
+    let mut literal = String::new();
+    for segment in parse_path_template(route_path) {
+        literal.push('/');
+        let (name, writer) = match segment {
+            PathSegment::Static(segment) => {
+                literal.push_str(&segment);
+                continue;
+            }
+            PathSegment::Param(name) => (name, quote! { write_path_segment }),
+            PathSegment::Wildcard(name) => (name, quote! { write_path_segments }),
+        };
+
+        if !literal.is_empty() {
+            steps.push(quote! { w.write_str(#literal) });
+            literal.clear();
+        }
+        let index = variant
+            .params
+            .iter()
+            .position(|param| param == &name)
+            .expect("path parameters are collected from the template");
+        let field = &variant.fields[index];
+        steps.push(quote! { #helpers #writer(w, #field) });
+    }
+    if !literal.is_empty() {
+        steps.push(quote! { w.write_str(#literal) });
+    }
+
+    let (last, init) = steps
+        .split_last()
+        .expect("a route with parameters has at least one segment");
+    let statements = quote! {
+        #(#init?;)*
+        #last
+    };
+    if braced && !init.is_empty() {
+        quote! { { #statements } }
+    } else {
+        statements
+    }
+}
@@ -0,0 +1,94 @@
+use super::LinkVariant;
+use serde_json::{Map, Value, json};
+
+/// JSON Schema (draft 2020-12) of `Link` serialized as
+/// `{"route": "GetUser", "params": {"user_id": "..."}}`, or with groups as
+/// `{"group": "Users", "link": {"route": "Get", "params": {..}}}`
+pub fn generate_json_schema(variants: &[LinkVariant]) -> String {
+    let mut groups: Vec<(String, Vec<Value>)> = Vec::new();
+    for variant in variants {
+        let group = variant
+            .group
+            .as_ref()
+            .map(|group| group.to_string())
+            .unwrap_or_default();
+        let schema = variant_schema(variant);
+        match groups.iter_mut().find(|(name, _)| *name == group) {
+            Some((_, alternatives)) => alternatives.push(schema),
+            None => groups.push((group, vec![schema])),
+        }
+    }
+
+    let alternatives: Vec<Value> = if variants.iter().any(|variant| variant.group.is_some()) {
+        groups
+            .into_iter()
+            .map(|(group, alternatives)| {
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "group": { "const": group },
+                        "link": { "oneOf": alternatives },
+                    },
+                    "required": ["group", "link"],
+                    "additionalProperties": false,
+                })
+            })
+            .collect()
+    } else {
+        groups
+            .into_iter()
+            .flat_map(|(_, alternatives)| alternatives)
+            .collect()
+    };
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Link",
+        "oneOf": alternatives,
+    })
+    .to_string()
+}
+
+/// `{"route": .., "params": {..}}` of a single variant
+fn variant_schema(variant: &LinkVariant) -> Value {
+    let name = variant.ident.to_string();
+    let description = format!("{} {}", variant.route.method, variant.route.path);
+    if variant.is_unit() {
+        return json!({
+            "type": "object",
+            "description": description,
+            "properties": { "route": { "const": name } },
+            "required": ["route"],
+            "additionalProperties": false,
+        });
+    }
+
+    let mut properties = Map::new();
+    for field in &variant.fields {
+        properties.insert(field.to_string(), json!({ "type": "string" }));
+    }
+    if variant.query.is_some() {
+        properties.insert("query".to_string(), json!({ "type": ["object", "null"] }));
+    }
+    let required: Vec<String> = variant
+        .fields
+        .iter()
+        .map(|field| field.to_string())
+        .collect();
+
+    json!({
+        "type": "object",
+        "description": description,
+        "properties": {
+            "route": { "const": name },
+            "params": {
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            },
+        },
+        "required": ["route", "params"],
+        "additionalProperties": false,
+    })
+}
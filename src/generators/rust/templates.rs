@@ -0,0 +1,194 @@
+use super::LinkVariant;
+use crate::config::TemplateEngine;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// `Link::from_name`, `LinkError` and the `link` helpers of the configured engines,
+/// or nothing when no engine is configured
+pub fn generate_template_helpers(
+    variants: &[LinkVariant],
+    engines: &[TemplateEngine],
+) -> (TokenStream, TokenStream) {
+    if engines.is_empty() {
+        return (quote! {}, quote! {});
+    }
+
+    let arms = variants.iter().map(|variant| {
+        let name = variant.qualified_name();
+        let fields = &variant.fields;
+        let keys: Vec<String> = fields.iter().map(|field| field.to_string()).collect();
+        let query = variant.query.as_ref().map(|_| quote! { query: None });
+
+        if fields.is_empty() {
+            let link = if variant.is_unit() {
+                variant.link_path(&quote! {}, None)
+            } else {
+                variant.link_path(&quote! {}, Some(quote! { #query }))
+            };
+            return quote! {
+                #name => {
+                    if let Some((key, _)) = params.into_iter().next() {
+                        return Err(LinkError::UnexpectedParam {
+                            route: #name,
+                            param: key.as_ref().to_string(),
+                        });
+                    }
+                    Ok(#link)
+                }
+            };
+        }
+
+        let query = query.map(|query| quote! { , #query });
+        let link = variant.link_path(
+            &quote! {},
+            Some(quote! {
+                #(#fields: #fields.ok_or(LinkError::MissingParam { route: #name, param: #keys })?),*
+                #query
+            }),
+        );
+        quote! {
+            #name => {
+                #(let mut #fields: Option<String> = None;)*
+                for (key, value) in params {
+                    match key.as_ref() {
+                        #(#keys => #fields = Some(value.to_string()),)*
+                        other => {
+                            return Err(LinkError::UnexpectedParam {
+                                route: #name,
+                                param: other.to_string(),
+                            });
+                        }
+                    }
+                }
+                Ok(#link)
+            }
+        }
+    });
+
+    let from_name = quote! {
+        /// Link for a variant name and named parameter values, e.g. from a template:
+        /// `Link::from_name("GetUser", [("user_id", 42)])`
+        pub fn from_name<K: AsRef<str>, V: std::fmt::Display>(
+            name: &str,
+            params: impl IntoIterator<Item = (K, V)>,
+        ) -> Result<Link, LinkError> {
+            match name {
+                #(#arms,)*
+                _ => Err(LinkError::UnknownRoute {
+                    name: name.to_string(),
+                }),
+            }
+        }
+    };
+
+    let engine_helpers = engines.iter().map(|engine| match engine {
+        TemplateEngine::Tera => quote! {
+            /// Register `link(name="GetUser", user_id=...)` returning the path of a route
+            pub fn register_tera_link(tera: &mut tera::Tera) {
+                tera.register_function(
+                    "link",
+                    |args: &std::collections::HashMap<String, tera::Value>| -> tera::Result<tera::Value> {
+                        let name = match args.get("name") {
+                            Some(tera::Value::String(name)) => name,
+                            _ => {
+                                return Err(tera::Error::msg(
+                                    "link() requires a string `name` argument, e.g. link(name=\"GetUser\")",
+                                ));
+                            }
+                        };
+                        let params = args
+                            .iter()
+                            .filter(|(key, _)| key.as_str() != "name")
+                            .map(|(key, value)| match value {
+                                tera::Value::String(value) => (key, value.clone()),
+                                other => (key, other.to_string()),
+                            });
+                        Link::from_name(name, params)
+                            .map(|link| tera::Value::String(link.to_path().into_owned()))
+                            .map_err(|e| tera::Error::msg(e.to_string()))
+                    },
+                );
+            }
+        },
+        TemplateEngine::Minijinja => quote! {
+            /// Register `link(name="GetUser", user_id=...)` returning the path of a route
+            pub fn register_minijinja_link(env: &mut minijinja::Environment<'_>) {
+                env.add_function(
+                    "link",
+                    |kwargs: minijinja::value::Kwargs| -> Result<String, minijinja::Error> {
+                        let name: String = kwargs.get("name")?;
+                        let params = kwargs
+                            .args()
+                            .filter(|key| *key != "name")
+                            .map(|key| Ok((key, kwargs.get::<minijinja::Value>(key)?)))
+                            .collect::<Result<Vec<_>, minijinja::Error>>()?;
+                        Link::from_name(&name, params)
+                            .map(|link| link.to_path().into_owned())
+                            .map_err(|e| {
+                                minijinja::Error::new(minijinja::ErrorKind::InvalidOperation, e.to_string())
+                            })
+                    },
+                );
+            }
+        },
+        TemplateEngine::Askama => quote! {
+            /// Path of the named route, for Askama templates:
+            /// `{{ crate::links::link("GetUser", [("user_id", user.id)])? }}`
+            pub fn link<K: AsRef<str>, V: std::fmt::Display>(
+                name: &str,
+                params: &[(K, V)],
+            ) -> Result<String, LinkError> {
+                Link::from_name(name, params.iter().map(|(key, value)| (key, value)))
+                    .map(|link| link.to_path().into_owned())
+            }
+        },
+    });
+
+    let support = quote! {
+        /// Why `Link::from_name` could not build a link
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum LinkError {
+            /// No route has this variant name
+            UnknownRoute { name: String },
+            /// A path parameter of the route was not given
+            MissingParam {
+                route: &'static str,
+                param: &'static str,
+            },
+            /// A parameter the route does not take was given
+            UnexpectedParam { route: &'static str, param: String },
+        }
+
+        impl std::fmt::Display for LinkError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let template = |name: &str| {
+                    ROUTES
+                        .iter()
+                        .find(|route| route.name == name)
+                        .map_or("", |route| route.template)
+                };
+                match self {
+                    LinkError::UnknownRoute { name } => {
+                        write!(f, "unknown route `{name}`")?;
+                        match ROUTES.iter().find(|route| route.name.eq_ignore_ascii_case(name)) {
+                            Some(route) => write!(f, ", did you mean `{}`?", route.name),
+                            None => Ok(()),
+                        }
+                    }
+                    LinkError::MissingParam { route, param } => {
+                        write!(f, "route `{route}` ({}) requires the parameter `{param}`", template(route))
+                    }
+                    LinkError::UnexpectedParam { route, param } => {
+                        write!(f, "route `{route}` ({}) has no parameter `{param}`", template(route))
+                    }
+                }
+            }
+        }
+
+        impl std::error::Error for LinkError {}
+
+        #(#engine_helpers)*
+    };
+
+    (from_name, support)
+}
@@ -0,0 +1,187 @@
+use super::{
+    builder_name,
+    client::{path_param_types, resolve_type},
+    collect_variants, format_rust_code, generated_header,
+};
+use crate::{RouteInfo, config::Config, generators::CodeGenerator};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::path::PathBuf;
+
+/// `TestLinks` trait for `axum_test::TestServer`, as handed out by Loco's
+/// `request` test helper, with typed requests for every route.
+///
+/// The generated code needs `axum`, `axum-test` and `serde` in the test crate.
+pub struct RustTestLinksGenerator;
+
+impl CodeGenerator for RustTestLinksGenerator {
+    type Config = Config;
+    type Output = String;
+
+    fn generate(
+        routes: &[RouteInfo],
+        config: &Self::Config,
+    ) -> Result<Self::Output, Box<dyn std::error::Error>> {
+        let helpers_config = &config.rust.test_helpers;
+        let types_module = helpers_config.types_module.as_deref();
+        let links_module = helpers_config
+            .links_module
+            .as_deref()
+            .unwrap_or("crate::links");
+        let links_module: syn::Path = syn::parse_str(links_module).map_err(|e| {
+            format!(
+                "Invalid rust.test_helpers.links_module '{}': {}",
+                links_module, e
+            )
+        })?;
+
+        let variants = collect_variants(routes, config)?;
+        let mut declarations = Vec::new();
+        let mut implementations = Vec::new();
+
+        for variant in &variants {
+            let route = variant.route;
+            let fields = &variant.fields;
+            let path_types = path_param_types(variant);
+
+            // Path parameters first, then the body, the token and the query
+            let mut parameters: Vec<TokenStream> = fields
+                .iter()
+                .zip(&path_types)
+                .map(|(field, path_type)| match path_type {
+                    Some(path_type) => quote! { #field: #path_type },
+                    None => quote! { #field: &str },
+                })
+                .collect();
+            let mut arguments: Vec<TokenStream> =
+                fields.iter().map(|field| quote! { #field }).collect();
+
+            let mut chain = Vec::new();
+            if let Some(body) = route.handler_info.body_param.as_deref() {
+                let body_type = resolve_type(body, route, &config.rust, types_module);
+                parameters.push(quote! { body: &#body_type });
+                arguments.push(quote! { body });
+                chain.push(quote! { .json(body) });
+            }
+            let query = route.handler_info.query_params.as_deref().map(|query| {
+                let query_type = resolve_type(query, route, &config.rust, types_module);
+                quote! { query: Option<&#query_type> }
+            });
+            if route.handler_info.requires_auth {
+                parameters.push(quote! { token: &str });
+                arguments.push(quote! { token });
+                chain.push(quote! { .authorization_bearer(token) });
+            }
+
+            let module = quote! { #links_module:: };
+            let link = if variant.is_unit() {
+                variant.link_path(&module, None)
+            } else {
+                let query_field = variant.query.as_ref().map(|_| quote! { query: None });
+                let initializers = fields
+                    .iter()
+                    .map(|field| quote! { #field: #field.to_string() })
+                    .chain(query_field);
+                variant.link_path(&module, Some(quote! { #(#initializers),* }))
+            };
+
+            let (return_type, decode) = match route.handler_info.return_type.found_type.as_deref() {
+                Some(found_type) => {
+                    let return_type = resolve_type(found_type, route, &config.rust, types_module);
+                    (return_type, quote! { response.json() })
+                }
+                None => (quote! { axum_test::TestResponse }, quote! { response }),
+            };
+
+            let request = quote! { self.link_request(&#link) #(#chain)* };
+            let request = match query {
+                Some(query) => {
+                    parameters.push(query);
+                    arguments.push(quote! { query });
+                    quote! {
+                        let request = #request;
+                        match query {
+                            Some(query) => request.add_query_params(query),
+                            None => request,
+                        }
+                    }
+                }
+                None => request,
+            };
+
+            let fn_name = builder_name(variant);
+            let request_fn_name = quote::format_ident!("{}_request", fn_name);
+            let request_doc = format!(" Request for `{} {}`", route.method, route.path);
+            let doc = format!(
+                " Send `{} {}`, assert a successful status and decode the response",
+                route.method, route.path
+            );
+
+            declarations.push(quote! {
+                #[doc = #request_doc]
+                fn #request_fn_name(&self, #(#parameters),*) -> axum_test::TestRequest;
+
+                #[doc = #doc]
+                fn #fn_name(&self, #(#parameters),*) -> impl std::future::Future<Output = #return_type>;
+            });
+            implementations.push(quote! {
+                fn #request_fn_name(&self, #(#parameters),*) -> axum_test::TestRequest {
+                    #request
+                }
+
+                async fn #fn_name(&self, #(#parameters),*) -> #return_type {
+                    let response = self.#request_fn_name(#(#arguments),*).await;
+                    response.assert_status_success();
+                    #decode
+                }
+            });
+        }
+
+        let method = if config.rust.http.unwrap_or(false) {
+            quote! { link.method() }
+        } else {
+            quote! {
+                axum::http::Method::from_bytes(link.method().as_bytes())
+                    .expect("valid HTTP method")
+            }
+        };
+
+        let generated = quote! {
+            /// Typed requests for every application route, with paths built from `Link`
+            pub trait TestLinks {
+                /// Request with the method and path of `link`
+                fn link_request(&self, link: &#links_module::Link) -> axum_test::TestRequest;
+
+                #(#declarations)*
+            }
+
+            impl TestLinks for axum_test::TestServer {
+                fn link_request(&self, link: &#links_module::Link) -> axum_test::TestRequest {
+                    self.method(#method, &link.to_path())
+                }
+
+                #(#implementations)*
+            }
+        };
+
+        format_rust_code(generated)
+    }
+}
+
+impl RustTestLinksGenerator {
+    /// The helpers file with its `@generated` header, or none when
+    /// `rust.test_helpers.output_path` is unset
+    pub fn generate_files(
+        routes: &[RouteInfo],
+        config: &Config,
+    ) -> Result<Vec<(PathBuf, String)>, Box<dyn std::error::Error>> {
+        let Some(output_path) = &config.rust.test_helpers.output_path else {
+            return Ok(Vec::new());
+        };
+        let code = Self::generate(routes, config)?;
+        Ok(vec![(
+            output_path.clone(),
+            format!("{}{}", generated_header(config), code),
+        )])
+    }
+}
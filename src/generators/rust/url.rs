@@ -0,0 +1,106 @@
+use super::LinkVariant;
+use crate::{RouteInfo, config::RustConfig};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Path of the `Query<T>` DTO for a route, when typed query fields are enabled.
+///
+/// Types are resolved inside the controller module they were used in, e.g.
+/// `crate::controllers::users::ListQuery`.
+pub fn query_type(route: &RouteInfo, config: &RustConfig) -> Option<syn::Path> {
+    if !config.query_fields.unwrap_or(false) {
+        return None;
+    }
+    let type_name = route.handler_info.query_params.as_deref()?;
+
+    let controllers_module = config
+        .controllers_module
+        .as_deref()
+        .unwrap_or("crate::controllers");
+    let full_path = if type_name.contains("::") {
+        type_name.to_string()
+    } else {
+        format!(
+            "{}::{}::{}",
+            controllers_module, route.controller, type_name
+        )
+    };
+
+    match syn::parse_str(&full_path) {
+        Ok(path) => Some(path),
+        Err(_) => {
+            println!(
+                "cargo:warning=Skipping query field of {} {}: '{}' is not a type path",
+                route.method, route.path, full_path
+            );
+            None
+        }
+    }
+}
+
+/// `to_path_and_query`, `to_url_with_base` and, with a configured base URL, `to_url`
+pub fn generate_url_methods(variants: &[LinkVariant], config: &RustConfig) -> TokenStream {
+    let query_arms: Vec<TokenStream> = variants
+        .iter()
+        .filter(|variant| variant.query.is_some())
+        .map(|variant| {
+            let pattern = variant.link_path(&quote! {}, Some(quote! { query: Some(query), .. }));
+            quote! {
+                #pattern => query_string(query)
+            }
+        })
+        .collect();
+
+    let to_path_and_query = if query_arms.is_empty() {
+        quote! {
+            /// Path including the query string; no route takes query parameters
+            pub fn to_path_and_query(&self) -> String {
+                self.to_path().into_owned()
+            }
+        }
+    } else {
+        quote! {
+            /// Path followed by the serialized `query` field, if any
+            pub fn to_path_and_query(&self) -> String {
+                fn query_string<T: serde::Serialize>(query: &T) -> String {
+                    serde_urlencoded::to_string(query)
+                        .expect("query parameters must serialize to a flat list of key/value pairs")
+                }
+
+                let path = self.to_path();
+                let query = match self {
+                    #(#query_arms,)*
+                    _ => return path.into_owned(),
+                };
+                if query.is_empty() {
+                    path.into_owned()
+                } else {
+                    format!("{path}?{query}")
+                }
+            }
+        }
+    };
+
+    let to_url = config.base_url.as_ref().map(|base_url| {
+        quote! {
+            /// Base URL configured at generation time
+            pub const BASE_URL: &'static str = #base_url;
+
+            /// Absolute URL against [`Link::BASE_URL`]
+            pub fn to_url(&self) -> String {
+                self.to_url_with_base(Self::BASE_URL)
+            }
+        }
+    });
+
+    quote! {
+        #to_path_and_query
+
+        /// Absolute URL against the given base, e.g. `https://example.com`
+        pub fn to_url_with_base(&self, base_url: &str) -> String {
+            format!("{}{}", base_url.trim_end_matches('/'), self.to_path_and_query())
+        }
+
+        #to_url
+    }
+}
@@ -2,6 +2,8 @@ use crate::{
     RouteInfo,
     config::TypeScriptConfig,
     generators::{CodeGenerator, typescript::TypeImportManager},
+    utils::case::CaseStyle,
+    utils::path::PathSegment,
 };
 use std::collections::HashSet;
 use ts_quote::ts_string;
@@ -14,14 +16,14 @@ impl CodeGenerator for TypeScriptClientGenerator {
 
     fn generate(
         routes: &[RouteInfo],
-        _config: &Self::Config,
+        config: &Self::Config,
     ) -> Result<Self::Output, Box<dyn std::error::Error>> {
         let mut imports = Vec::new();
         let mut client_methods = Vec::new();
         let mut interfaces = Vec::new();
 
         // Initialize type import manager and collect types
-        let mut type_manager = TypeImportManager::new();
+        let mut type_manager = TypeImportManager::for_config(config);
         type_manager.collect_from_routes(routes);
 
         // Tanstack query imports
@@ -37,21 +39,35 @@ impl CodeGenerator for TypeScriptClientGenerator {
         // Add type imports from the shared manager
         imports.extend(type_manager.generate_imports());
 
+        // Zod schemas used to validate request bodies and responses
+        let use_schemas = config.generate_zod_schemas.unwrap_or(false);
+        if use_schemas {
+            let schema_names = super::zod::client_schema_names(routes)
+                .into_iter()
+                .collect::<Vec<_>>();
+            if !schema_names.is_empty() {
+                let schema_names_str = schema_names.join(", ");
+                imports.push(ts_string! {
+                    import { #schema_names_str } from "./schemas";
+                });
+            }
+        }
+
         // Generate the base HTTP client with auth support
-        let http_client = generate_http_client();
+        let http_client = generate_http_client(config);
 
         for route in routes {
-            let method_name = crate::utils::case::convert_to_case(&route.name, "camel");
+            let names = super::RouteNames::new(&route.name, config);
             let path_params = crate::utils::path::extract_parameters_from_path(&route.path);
 
             // Generate interfaces for path parameters if needed
             if !path_params.is_empty() {
-                let interface = generate_ts_interface(&method_name, "Params", &path_params);
+                let interface = generate_ts_interface(&names.params, &path_params);
                 interfaces.push(interface);
             }
 
             // Generate client method
-            let client_method = generate_client_method(route, &method_name, &path_params);
+            let client_method = generate_client_method(route, &names, &path_params, use_schemas);
             client_methods.push(client_method);
         }
 
@@ -74,12 +90,19 @@ impl CodeGenerator for TypeScriptClientGenerator {
         };
 
         // Format the TypeScript code
-        let formatted = super::format_ts_code(&ts_code.to_string())?;
+        let formatted = super::format_ts_code("client.ts", &ts_code.to_string(), &config.format)?;
         Ok(formatted)
     }
 }
 
-fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[String]) -> String {
+fn generate_client_method(
+    route: &RouteInfo,
+    names: &super::RouteNames,
+    path_params: &[String],
+    use_schemas: bool,
+) -> String {
+    let method_name = &names.method;
+    let params_type = &names.params;
     let _method_upper = route.method.to_uppercase();
     let path_template = generate_ts_path_template(&route.path, path_params);
 
@@ -100,6 +123,24 @@ fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[S
     let has_query_params = route.handler_info.query_params.is_some();
     let has_body = route.method != "GET" && body_type != "void";
 
+    // Options passed to the underlying ApiClient call
+    let mut request_options = format!(
+        "{{ requiresAuth: {requires_auth}, signal: config?.signal, route: \"{method_name}\""
+    );
+    if use_schemas && has_body {
+        request_options.push_str(&format!(
+            ", bodySchema: {}",
+            super::zod::schema_name(body_type)
+        ));
+    }
+    if use_schemas && let Some(found_type) = &route.handler_info.return_type.found_type {
+        request_options.push_str(&format!(
+            ", responseSchema: {}",
+            super::zod::response_schema_name(found_type)
+        ));
+    }
+    request_options.push_str(" }");
+
     if route.method == "GET" {
         // GET request with various parameter combinations
         if !has_path_params && !has_query_params {
@@ -107,19 +148,15 @@ fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[S
             ts_string! {
                 #method_name: async (config?: { signal?: AbortSignal }): Promise<#return_type> => {
                     const url = #path_template;
-                    return apiClient.get<#return_type, #error_union>(url, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.get<#return_type, #error_union>(url, #request_options);
                 },
             }
         } else if has_path_params && !has_query_params {
             // Only path parameters
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 #method_name: async (params: #params_type, config?: { signal?: AbortSignal }): Promise<#return_type> => {
                     const url = #path_template;
-                    return apiClient.get<#return_type, #error_union>(url, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.get<#return_type, #error_union>(url, #request_options);
                 },
             }
         } else if !has_path_params && has_query_params {
@@ -137,15 +174,11 @@ fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[S
                     if (queryStr) {
                         url += "?"+queryStr;
                     }
-                    return apiClient.get<#return_type, #error_union>(url, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.get<#return_type, #error_union>(url, #request_options);
                 },
             }
         } else {
             // Both path and query parameters
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 #method_name: async (params: #params_type, query: #query_type, config?: { signal?: AbortSignal }): Promise<#return_type> => {
                     let url = #path_template;
@@ -159,7 +192,7 @@ fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[S
                     if (queryStr) {
                         url += "?"+queryStr;
                     }
-                    return apiClient.get<#return_type, #error_union>(url, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.get<#return_type, #error_union>(url, #request_options);
                 },
             }
         }
@@ -178,19 +211,15 @@ fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[S
             ts_string! {
                 #method_name: async (config?: { signal?: AbortSignal }): Promise<#return_type> => {
                     const url = #path_template;
-                    return apiClient.#method_call<#return_type, #error_union>(url, undefined, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.#method_call<#return_type, #error_union>(url, undefined, #request_options);
                 },
             }
         } else if has_path_params && !has_query_params && !has_body {
             // Only path parameters, no body
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 #method_name: async (params: #params_type, config?: { signal?: AbortSignal }): Promise<#return_type> => {
                     const url = #path_template;
-                    return apiClient.#method_call<#return_type, #error_union>(url, undefined, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.#method_call<#return_type, #error_union>(url, undefined, #request_options);
                 },
             }
         } else if !has_path_params && has_query_params && !has_body {
@@ -208,15 +237,11 @@ fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[S
                     if (queryStr) {
                         url += "?"+queryStr;
                     }
-                    return apiClient.#method_call<#return_type, #error_union>(url, undefined, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.#method_call<#return_type, #error_union>(url, undefined, #request_options);
                 },
             }
         } else if has_path_params && has_query_params && !has_body {
             // Path and query parameters, no body
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 #method_name: async (params: #params_type, query: #query_type, config?: { signal?: AbortSignal }): Promise<#return_type> => {
                     let url = #path_template;
@@ -230,7 +255,7 @@ fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[S
                     if (queryStr) {
                         url += "?"+queryStr;
                     }
-                    return apiClient.#method_call<#return_type, #error_union>(url, undefined, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.#method_call<#return_type, #error_union>(url, undefined, #request_options);
                 },
             }
         } else if !has_path_params && !has_query_params && has_body {
@@ -238,19 +263,15 @@ fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[S
             ts_string! {
                 #method_name: async (body: #body_type, config?: { signal?: AbortSignal }): Promise<#return_type> => {
                     const url = #path_template;
-                    return apiClient.#method_call<#return_type, #error_union>(url, body, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.#method_call<#return_type, #error_union>(url, body, #request_options);
                 },
             }
         } else if has_path_params && !has_query_params && has_body {
             // Path parameters and body
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 #method_name: async (params: #params_type, body: #body_type, config?: { signal?: AbortSignal }): Promise<#return_type> => {
                     const url = #path_template;
-                    return apiClient.#method_call<#return_type, #error_union>(url, body, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.#method_call<#return_type, #error_union>(url, body, #request_options);
                 },
             }
         } else if !has_path_params && has_query_params && has_body {
@@ -268,15 +289,11 @@ fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[S
                     if (queryStr) {
                         url += "?"+queryStr;
                     }
-                    return apiClient.#method_call<#return_type, #error_union>(url, body, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.#method_call<#return_type, #error_union>(url, body, #request_options);
                 },
             }
         } else {
             // All three: path parameters, query parameters, and body
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 #method_name: async (params: #params_type, query: #query_type, body: #body_type, config?: { signal?: AbortSignal }): Promise<#return_type> => {
                     let url = #path_template;
@@ -290,7 +307,7 @@ fn generate_client_method(route: &RouteInfo, method_name: &str, path_params: &[S
                     if (queryStr) {
                         url += "?"+queryStr;
                     }
-                    return apiClient.#method_call<#return_type, #error_union>(url, body, { requiresAuth: #requires_auth, signal: config?.signal });
+                    return apiClient.#method_call<#return_type, #error_union>(url, body, #request_options);
                 },
             }
         }
@@ -311,39 +328,39 @@ fn generate_route_error_union(route: &RouteInfo) -> String {
 
 fn generate_ts_path_template(path: &str, _params: &[String]) -> String {
     let mut template = String::new();
-    let segments: Vec<&str> = path.split('/').collect();
-
-    for (i, segment) in segments.iter().enumerate() {
-        if segment.is_empty() {
-            continue;
-        }
-
-        if i > 0 {
-            template.push('/');
-        }
 
-        if segment.starts_with('{') && segment.ends_with('}') {
-            let param_name = &segment[1..segment.len() - 1];
-            let ts_param_name = crate::utils::case::convert_to_case(param_name, "camel");
-            template.push_str(&format!("${{params.{}}}", ts_param_name));
-        } else {
-            template.push_str(segment);
+    for segment in crate::utils::path::parse_path_template(path) {
+        template.push('/');
+        match segment {
+            PathSegment::Static(segment) => template.push_str(&segment),
+            // Values are percent-encoded; catch-alls keep their `/` separators
+            PathSegment::Param(name) => {
+                let ts_param_name = crate::utils::case::convert_to_case(&name, CaseStyle::Camel);
+                template.push_str(&format!(
+                    "${{encodeURIComponent(params.{})}}",
+                    ts_param_name
+                ));
+            }
+            PathSegment::Wildcard(name) => {
+                let ts_param_name = crate::utils::case::convert_to_case(&name, CaseStyle::Camel);
+                template.push_str(&format!(
+                    "${{params.{}.split(\"/\").map(encodeURIComponent).join(\"/\")}}",
+                    ts_param_name
+                ));
+            }
         }
     }
 
-    let temp = if template.is_empty() {
-        "/".to_string()
-    } else if !template.starts_with('/') {
-        format!("/{}", template)
-    } else {
-        template
-    };
-    format!("`{temp}`")
+    if template.is_empty() {
+        template.push('/');
+    }
+    format!("`{template}`")
 }
 
-fn generate_http_client() -> String {
-    // ... (keep the existing http client implementation unchanged)
-    // This function remains the same as in your original code
+fn generate_http_client(config: &TypeScriptConfig) -> String {
+    let validate_requests = config.validate_requests.unwrap_or(false);
+    let validate_responses = config.validate_responses.unwrap_or(false);
+
     ts_string! {
         // Base error type that comes from the server
         export type RawApiError = {
@@ -377,17 +394,79 @@ fn generate_http_client() -> String {
             );
         }
 
+        // Structural type satisfied by Zod schemas, so the client does not depend on zod
+        export type Schema = {
+            safeParse(data: unknown): { success: true } | { success: false; error: { issues: Array<SchemaIssue> } };
+        };
+
+        export type SchemaIssue = {
+            path: Array<PropertyKey>;
+            message: string;
+        };
+
+        // Thrown before sending when a request body does not satisfy its schema
+        export class RequestValidationError extends Error {
+            route: string;
+            issues: Array<SchemaIssue>;
+
+            constructor(route: string, issues: Array<SchemaIssue>) {
+                super("Invalid request body for " + route + ": " + issues.map((issue) => issue.path.map(String).join(".") + " " + issue.message).join(", "));
+                this.name = "RequestValidationError";
+                this.route = route;
+                this.issues = issues;
+            }
+        }
+
+        // Thrown when a response body does not satisfy the schema of its route
+        export class ResponseValidationError extends Error {
+            route: string;
+            path: string;
+            issues: Array<SchemaIssue>;
+
+            constructor(route: string, issues: Array<SchemaIssue>) {
+                const path = issues.length > 0 ? issues[0].path.map(String).join(".") : "";
+                super("Invalid response for " + route + " at " + (path || "<root>") + ": " + (issues.length > 0 ? issues[0].message : "unknown error"));
+                this.name = "ResponseValidationError";
+                this.route = route;
+                this.path = path;
+                this.issues = issues;
+            }
+        }
+
+        export type RequestOptions = {
+            requiresAuth?: boolean;
+            signal?: AbortSignal;
+            route?: string;
+            bodySchema?: Schema;
+            responseSchema?: Schema;
+        };
+
         // Base HTTP client with authentication support
         class ApiClient {
             private baseUrl: string = "";
             private getToken?: () => Promise<string | null>;
+            validateRequests: boolean;
+            validateResponses: boolean;
 
-            constructor(config?: { baseUrl?: string; getToken?: () => Promise<string | null> }) {
+            constructor(config?: { baseUrl?: string; getToken?: () => Promise<string | null>; validateRequests?: boolean; validateResponses?: boolean }) {
                 this.baseUrl = config?.baseUrl || "";
                 this.getToken = config?.getToken;
+                this.validateRequests = config?.validateRequests ?? false;
+                this.validateResponses = config?.validateResponses ?? false;
+            }
+
+            // Validate a request body against its schema when enabled
+            private checkBody(data: unknown, options: RequestOptions): void {
+                if (!this.validateRequests || !options.bodySchema) {
+                    return;
+                }
+                const result = options.bodySchema.safeParse(data);
+                if (!result.success) {
+                    throw new RequestValidationError(options.route ?? "unknown", result.error.issues);
+                }
             }
 
-            async request<T, E = ApiError>(url: string, options: RequestInit & { requiresAuth?: boolean } = {}): Promise<T> {
+            async request<T, E = ApiError>(url: string, options: RequestInit & { requiresAuth?: boolean; route?: string; responseSchema?: Schema } = {}): Promise<T> {
                 const headers = new Headers(options.headers as Record<string, string>);
 
                 // Set Content-Type for requests with body
@@ -421,7 +500,17 @@ fn generate_http_client() -> String {
                     return null;
                 }
 
-                return response.json() as Promise<T>;
+                const data = await response.json();
+
+                // Check the payload against the route's schema when enabled
+                if (this.validateResponses && options.responseSchema) {
+                    const result = options.responseSchema.safeParse(data);
+                    if (!result.success) {
+                        throw new ResponseValidationError(options.route ?? "unknown", result.error.issues);
+                    }
+                }
+
+                return data as T;
             }
 
             private transformError(rawError: RawApiError): ApiError {
@@ -450,47 +539,61 @@ fn generate_http_client() -> String {
                 };
             }
 
-            async get<T, E = ApiError>(url: string, options: { requiresAuth?: boolean; signal?: AbortSignal } = {}): Promise<T> {
+            async get<T, E = ApiError>(url: string, options: RequestOptions = {}): Promise<T> {
                 return this.request<T, E>(url, {
                     method: "GET",
                     requiresAuth: options.requiresAuth,
                     signal: options.signal,
+                    route: options.route,
+                    responseSchema: options.responseSchema,
                 });
             }
 
-            async post<T, E = ApiError>(url: string, data?: any, options: { requiresAuth?: boolean; signal?: AbortSignal } = {}): Promise<T> {
+            async post<T, E = ApiError>(url: string, data?: any, options: RequestOptions = {}): Promise<T> {
+                this.checkBody(data, options);
                 return this.request<T, E>(url, {
                     method: "POST",
                     body: data ? JSON.stringify(data) : undefined,
                     requiresAuth: options.requiresAuth,
                     signal: options.signal,
+                    route: options.route,
+                    responseSchema: options.responseSchema,
                 });
             }
 
-            async put<T, E = ApiError>(url: string, data?: any, options: { requiresAuth?: boolean; signal?: AbortSignal } = {}): Promise<T> {
+            async put<T, E = ApiError>(url: string, data?: any, options: RequestOptions = {}): Promise<T> {
+                this.checkBody(data, options);
                 return this.request<T, E>(url, {
                     method: "PUT",
                     body: data ? JSON.stringify(data) : undefined,
                     requiresAuth: options.requiresAuth,
                     signal: options.signal,
+                    route: options.route,
+                    responseSchema: options.responseSchema,
                 });
             }
 
-            async patch<T, E = ApiError>(url: string, data?: any, options: { requiresAuth?: boolean; signal?: AbortSignal } = {}): Promise<T> {
+            async patch<T, E = ApiError>(url: string, data?: any, options: RequestOptions = {}): Promise<T> {
+                this.checkBody(data, options);
                 return this.request<T, E>(url, {
                     method: "PATCH",
                     body: data ? JSON.stringify(data) : undefined,
                     requiresAuth: options.requiresAuth,
                     signal: options.signal,
+                    route: options.route,
+                    responseSchema: options.responseSchema,
                 });
             }
 
-            async delete<T, E = ApiError>(url: string, data?: any, options: { requiresAuth?: boolean; signal?: AbortSignal } = {}): Promise<T> {
+            async delete<T, E = ApiError>(url: string, data?: any, options: RequestOptions = {}): Promise<T> {
+                this.checkBody(data, options);
                 return this.request<T, E>(url, {
                     method: "DELETE",
                     body: data ? JSON.stringify(data) : undefined,
                     requiresAuth: options.requiresAuth,
                     signal: options.signal,
+                    route: options.route,
+                    responseSchema: options.responseSchema,
                 });
             }
         }
@@ -500,20 +603,17 @@ fn generate_http_client() -> String {
           getToken: async () => {
             return localStorage.getItem(TOKEN_KEY);
           },
+          validateRequests: #validate_requests,
+          validateResponses: #validate_responses,
         });
     }
 }
 
-fn generate_ts_interface(method_name: &str, suffix: &str, params: &[String]) -> String {
-    let interface_name = format!(
-        "{}{}",
-        crate::utils::case::convert_to_case(method_name, "pascal"),
-        suffix
-    );
+fn generate_ts_interface(interface_name: &str, params: &[String]) -> String {
     let mut fields = Vec::new();
 
     for param in params {
-        let field_name = crate::utils::case::convert_to_case(param, "camel");
+        let field_name = crate::utils::case::convert_to_case(param, CaseStyle::Camel);
         fields.push(ts_string! {
             #field_name: string;
         });
@@ -13,14 +13,14 @@ impl CodeGenerator for TypeScriptHooksGenerator {
 
     fn generate(
         routes: &[RouteInfo],
-        _config: &Self::Config,
+        config: &Self::Config,
     ) -> Result<Self::Output, Box<dyn std::error::Error>> {
         let mut imports = Vec::new();
         let mut hooks = Vec::new();
         let mut client_imports = Vec::new();
 
         // Initialize type import manager and collect types
-        let mut type_manager = TypeImportManager::new();
+        let mut type_manager = TypeImportManager::for_config(config);
         type_manager.collect_from_routes(routes);
 
         // Import necessary types from the client
@@ -37,20 +37,12 @@ impl CodeGenerator for TypeScriptHooksGenerator {
         imports.extend(type_manager.generate_imports());
 
         for route in routes {
-            let method_name = crate::utils::case::convert_to_case(&route.name, "camel");
-            let hook_name = format!(
-                "use{}",
-                crate::utils::case::convert_to_case(&route.name, "pascal")
-            );
+            let names = super::RouteNames::new(&route.name, config);
             let path_params = crate::utils::path::extract_parameters_from_path(&route.path);
 
             // Add imports for path parameters if needed
             if !path_params.is_empty() {
-                let interface_name = format!(
-                    "{}Params",
-                    crate::utils::case::convert_to_case(&method_name, "pascal")
-                );
-                client_imports.push(format!("type {interface_name}"));
+                client_imports.push(format!("type {}", names.params));
             }
 
             // Add imports for query parameters if needed
@@ -59,7 +51,7 @@ impl CodeGenerator for TypeScriptHooksGenerator {
             }
 
             // Generate hook with proper error union type
-            let hook = generate_ts_hook(route, &method_name, &hook_name, &path_params);
+            let hook = generate_ts_hook(route, &names, &path_params);
             hooks.push(hook);
         }
 
@@ -85,17 +77,19 @@ impl CodeGenerator for TypeScriptHooksGenerator {
         };
 
         // Format the TypeScript code
-        let formatted = super::format_ts_code(&ts_code.to_string())?;
+        let formatted = super::format_ts_code("api.ts", &ts_code.to_string(), &config.format)?;
         Ok(formatted)
     }
 }
 
 fn generate_ts_hook(
     route: &RouteInfo,
-    method_name: &str,
-    hook_name: &str,
+    names: &super::RouteNames,
     path_params: &[String],
 ) -> String {
+    let method_name = &names.method;
+    let hook_name = &names.hook;
+    let params_type = &names.params;
     let method_name_str = format!("\"{method_name}\"");
     let body_type = route.handler_info.body_param.as_deref().unwrap_or("void");
     let query_type = route.handler_info.query_params.as_deref().unwrap_or("void");
@@ -129,10 +123,6 @@ fn generate_ts_hook(
             }
         } else if has_path_params && !has_query_params {
             // Only path parameters
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 export function #hook_name(params: #params_type, options?: Omit<UseQueryOptions<#return_type, #error_type>, "queryKey">) {
                     return useQuery({
@@ -155,10 +145,6 @@ fn generate_ts_hook(
             }
         } else {
             // Both path and query parameters
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 export function #hook_name(params: #params_type, query: #query_type, options?: Omit<UseQueryOptions<#return_type, #error_type>, "queryKey">) {
                     return useQuery({
@@ -183,10 +169,6 @@ fn generate_ts_hook(
             }
         } else if has_path_params && !has_query_params && !has_body {
             // Only path parameters
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 export function #hook_name(options?: UseMutationOptions<#return_type, #error_type, #params_type, unknown>) {
                     return useMutation({
@@ -207,10 +189,6 @@ fn generate_ts_hook(
             }
         } else if has_path_params && has_query_params && !has_body {
             // Path and query parameters, no body
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 export function #hook_name(options?: UseMutationOptions<#return_type, #error_type, { params: #params_type, query: #query_type }, unknown>) {
                     return useMutation({
@@ -232,10 +210,6 @@ fn generate_ts_hook(
             }
         } else if has_path_params && !has_query_params && has_body {
             // Path parameters and body
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 export function #hook_name(options?: UseMutationOptions<#return_type, #error_type, { params: #params_type, body: #body_type }, unknown>) {
                     return useMutation({
@@ -258,10 +232,6 @@ fn generate_ts_hook(
             }
         } else {
             // All three: path parameters, query parameters, and body
-            let params_type = format!(
-                "{}Params",
-                crate::utils::case::convert_to_case(method_name, "pascal")
-            );
             ts_string! {
                 export function #hook_name(options?: UseMutationOptions<#return_type, #error_type, { params: #params_type, query: #query_type, body: #body_type }, unknown>) {
                     return useMutation({
@@ -1,21 +1,33 @@
-use crate::RouteInfo;
-use std::collections::HashSet;
+use crate::{RouteInfo, config::TypeScriptConfig};
+use std::collections::BTreeSet;
 use ts_quote::ts_string;
 
 /// Shared utilities for handling TypeScript type imports
 pub struct TypeImportManager {
-    pub type_imports: HashSet<String>,
-    pub error_imports: HashSet<String>,
+    pub type_imports: BTreeSet<String>,
+    pub error_imports: BTreeSet<String>,
+    /// Module to import all types from instead of the per-type bindings
+    pub types_module: Option<String>,
 }
 
 impl TypeImportManager {
     pub fn new() -> Self {
         Self {
-            type_imports: HashSet::new(),
-            error_imports: HashSet::new(),
+            type_imports: BTreeSet::new(),
+            error_imports: BTreeSet::new(),
+            types_module: None,
         }
     }
 
+    /// Import from the generated `types.ts` when it is enabled
+    pub fn for_config(config: &TypeScriptConfig) -> Self {
+        let mut manager = Self::new();
+        if config.generate_types.unwrap_or(false) {
+            manager.types_module = Some("./types".to_string());
+        }
+        manager
+    }
+
     /// Collect all importable types from routes
     pub fn collect_from_routes(&mut self, routes: &[RouteInfo]) {
         for route in routes {
@@ -88,21 +100,31 @@ impl TypeImportManager {
     pub fn generate_imports(&self) -> Vec<String> {
         let mut imports = Vec::new();
 
-        // Generate type imports
-        for type_name in &self.type_imports {
-            // FIXME get path from config
-            let import_path = format!("\"../../../bindings/{type_name}\"");
+        // All types come from a single generated module
+        if let Some(module) = &self.types_module {
+            let type_names: Vec<&String> = self.type_imports.union(&self.error_imports).collect();
+            if type_names.is_empty() {
+                return imports;
+            }
+
+            let names_str = type_names
+                .iter()
+                .map(|type_name| format!("type {type_name}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let import_path = format!("\"{module}\"");
             imports.push(
                 ts_string! {
-                    import { type #type_name } from #import_path;
+                    import { #names_str } from #import_path;
                 }
                 .to_string(),
             );
+            return imports;
         }
 
-        // Generate error type imports
-        for type_name in &self.error_imports {
-            let import_path = format!("\"../../../bindings/{type_name}\"");
+        // One import per type, in sorted order so output is stable across builds
+        for type_name in self.type_imports.union(&self.error_imports) {
+            let import_path = Self::binding_import_path(type_name);
             imports.push(
                 ts_string! {
                     import { type #type_name } from #import_path;
@@ -113,6 +135,12 @@ impl TypeImportManager {
 
         imports
     }
+
+    /// Quoted import path of a type generated into the bindings folder
+    pub fn binding_import_path(type_name: &str) -> String {
+        // FIXME get path from config
+        format!("\"../../../bindings/{type_name}\"")
+    }
 }
 
 impl Default for TypeImportManager {
@@ -1,10 +1,83 @@
 pub mod client;
 pub mod hooks;
 pub mod imports;
+pub mod types;
+pub mod zod;
+use crate::config::{QuoteStyle, TypeScriptConfig, TypeScriptFormatConfig};
+use crate::utils::case::{CaseStyle, convert_to_case};
 pub use client::*;
+use dprint_plugin_typescript::configuration::{
+    ConfigurationBuilder, QuoteStyle as DprintQuoteStyle, SemiColons, TrailingCommas,
+};
 pub use hooks::*;
 pub use imports::*;
-pub fn format_ts_code(code: &str) -> Result<String, Box<dyn std::error::Error>> {
-    // For now, we'll use a simple formatter since deno_ast might be heavy
-    Ok(code.to_string())
+pub use types::*;
+pub use zod::*;
+
+/// Names generated for a route, shared by the client and the hooks that import it
+pub struct RouteNames {
+    /// Client method, e.g. `getUsers`
+    pub method: String,
+    /// React Query hook, e.g. `useGetUsers`
+    pub hook: String,
+    /// Interface of the path parameters, e.g. `GetUsersParams`
+    pub params: String,
+}
+
+impl RouteNames {
+    pub fn new(route_name: &str, config: &TypeScriptConfig) -> Self {
+        RouteNames {
+            method: convert_to_case(route_name, config.method_case.unwrap_or(CaseStyle::Camel)),
+            hook: convert_to_case(
+                &format!("use_{}", route_name),
+                config.hook_case.unwrap_or(CaseStyle::Camel),
+            ),
+            params: convert_to_case(
+                &format!("{}_params", route_name),
+                config.interface_case.unwrap_or(CaseStyle::Pascal),
+            ),
+        }
+    }
+}
+
+/// Parse generated TypeScript with deno_ast, so generator bugs fail the build with
+/// a located error, and print it with dprint using the configured style
+pub fn format_ts_code(
+    file_name: &str,
+    code: &str,
+    format: &TypeScriptFormatConfig,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let parsed = deno_ast::parse_module(deno_ast::ParseParams {
+        specifier: deno_ast::ModuleSpecifier::parse(&format!("file:///{file_name}"))?,
+        text: code.into(),
+        media_type: deno_ast::MediaType::TypeScript,
+        capture_tokens: true,
+        scope_analysis: false,
+        maybe_syntax: None,
+    })
+    .map_err(|e| format!("Generated invalid TypeScript in {}: {}", file_name, e))?;
+
+    let quote_style = match format.quote_style.unwrap_or_default() {
+        QuoteStyle::Double => DprintQuoteStyle::AlwaysDouble,
+        QuoteStyle::Single => DprintQuoteStyle::AlwaysSingle,
+    };
+    let semi_colons = if format.semicolons.unwrap_or(true) {
+        SemiColons::Always
+    } else {
+        SemiColons::Asi
+    };
+
+    // Defaults follow Prettier so the output passes frontend lint checks untouched
+    let configuration = ConfigurationBuilder::new()
+        .line_width(format.line_width.unwrap_or(80))
+        .indent_width(format.indent_width.unwrap_or(2))
+        .use_tabs(format.use_tabs.unwrap_or(false))
+        .quote_style(quote_style)
+        .semi_colons(semi_colons)
+        .trailing_commas(TrailingCommas::OnlyMultiLine)
+        .build();
+
+    let formatted = dprint_plugin_typescript::format_parsed_source(&parsed, &configuration, None)
+        .map_err(|e| format!("Failed to format {}: {}", file_name, e))?;
+    Ok(formatted.unwrap_or_else(|| code.to_string()))
 }
@@ -0,0 +1,230 @@
+use crate::{
+    RouteInfo,
+    config::Config,
+    generators::{CodeGenerator, typescript::TypeImportManager},
+    mapping::{
+        EnumRepresentation, VariantPayload, WireDefinition, WireObject, WireShape, WireType,
+        WireVariant,
+    },
+};
+use ts_quote::ts_string;
+
+/// Generates `types.ts` with the exact JSON shape of every DTO used by the routes
+pub struct TypeScriptTypesGenerator;
+
+impl CodeGenerator for TypeScriptTypesGenerator {
+    type Config = Config;
+    type Output = String;
+
+    fn generate(
+        routes: &[RouteInfo],
+        config: &Self::Config,
+    ) -> Result<Self::Output, Box<dyn std::error::Error>> {
+        let registry = crate::parser::scan_type_definitions(config)?;
+
+        // Start from the same types the client would import
+        let mut type_manager = TypeImportManager::new();
+        type_manager.collect_from_routes(routes);
+        let roots = type_manager
+            .type_imports
+            .iter()
+            .chain(type_manager.error_imports.iter())
+            .map(String::as_str);
+
+        let (definitions, unresolved) = registry.resolve(roots);
+
+        // Types we could not find are re-exported from the bindings as before
+        let re_exports: Vec<String> = unresolved
+            .iter()
+            .map(|type_name| {
+                let import_path = TypeImportManager::binding_import_path(type_name);
+                ts_string! {
+                    export type { #type_name } from #import_path;
+                }
+                .to_string()
+            })
+            .collect();
+
+        let declarations: Vec<String> = definitions
+            .into_iter()
+            .map(generate_ts_declaration)
+            .collect();
+
+        let re_exports_str = re_exports.join("\n");
+        let declarations_str = declarations.join("\n");
+
+        let ts_code = ts_string! {
+            #re_exports_str
+
+            #declarations_str
+        };
+
+        let formatted =
+            super::format_ts_code("types.ts", &ts_code.to_string(), &config.typescript.format)?;
+        Ok(formatted)
+    }
+}
+
+/// Render a DTO definition as an exported TypeScript type alias
+pub fn generate_ts_declaration(definition: &WireDefinition) -> String {
+    let name = &definition.name;
+    let body = match &definition.shape {
+        WireShape::Object(object) => render_object(object),
+        WireShape::Alias(ty) => render_wire_type(ty),
+        WireShape::Enum {
+            representation,
+            variants,
+        } => render_enum(representation, variants),
+    };
+
+    ts_string! {
+        export type #name = #body;
+    }
+    .to_string()
+}
+
+/// Render the TypeScript type for a JSON value shape
+pub fn render_wire_type(ty: &WireType) -> String {
+    match ty {
+        WireType::String => "string".to_string(),
+        WireType::Number => "number".to_string(),
+        WireType::Boolean => "boolean".to_string(),
+        WireType::Null => "null".to_string(),
+        WireType::Unknown => "unknown".to_string(),
+        WireType::Nullable(inner) => format!("{} | null", render_wire_type(inner)),
+        WireType::Array(inner) => format!("Array<{}>", render_wire_type(inner)),
+        WireType::Tuple(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(render_wire_type)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        WireType::Record(value) => format!("Record<string, {}>", render_wire_type(value)),
+        WireType::Reference(name) => name.clone(),
+    }
+}
+
+fn render_object(object: &WireObject) -> String {
+    let fields: Vec<String> = object
+        .fields
+        .iter()
+        .map(|field| {
+            let optional = if field.optional { "?" } else { "" };
+            format!(
+                "{}{}: {};",
+                property_key(&field.name),
+                optional,
+                render_wire_type(&field.ty)
+            )
+        })
+        .collect();
+
+    let mut parts = Vec::new();
+    if !fields.is_empty() || object.flatten.is_empty() {
+        parts.push(format!("{{ {} }}", fields.join(" ")));
+    }
+    parts.extend(object.flatten.iter().map(render_wire_type));
+    parts.join(" & ")
+}
+
+fn render_enum(representation: &EnumRepresentation, variants: &[WireVariant]) -> String {
+    if variants.is_empty() {
+        return "never".to_string();
+    }
+
+    variants
+        .iter()
+        .map(|variant| {
+            if variant.untagged {
+                render_untagged_variant(&variant.payload)
+            } else {
+                render_variant(representation, variant)
+            }
+        })
+        // Intersections and nullable payloads need parentheses inside the union
+        .map(|variant| {
+            if variant.contains(" & ") || variant.contains(" | ") {
+                format!("({variant})")
+            } else {
+                variant
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+fn render_variant(representation: &EnumRepresentation, variant: &WireVariant) -> String {
+    // See `VariantPayload::Unknown`
+    if variant.payload == VariantPayload::Unknown {
+        return "unknown".to_string();
+    }
+    let name = string_literal(&variant.name);
+
+    match representation {
+        EnumRepresentation::External => match &variant.payload {
+            VariantPayload::Unit => name,
+            payload => format!(
+                "{{ {}: {} }}",
+                property_key(&variant.name),
+                render_untagged_variant(payload)
+            ),
+        },
+        EnumRepresentation::Internal { tag } => {
+            let tag_object = format!("{{ {}: {} }}", property_key(tag), name);
+            match &variant.payload {
+                VariantPayload::Unit => tag_object,
+                VariantPayload::Newtype(ty) => format!("{tag_object} & {}", render_wire_type(ty)),
+                VariantPayload::Struct(object) => {
+                    format!("{tag_object} & {}", render_object(object))
+                }
+                VariantPayload::Tuple(_) | VariantPayload::Unknown => {
+                    unreachable!("internally tagged tuple variants are mapped as unknown")
+                }
+            }
+        }
+        EnumRepresentation::Adjacent { tag, content } => match &variant.payload {
+            VariantPayload::Unit => format!("{{ {}: {} }}", property_key(tag), name),
+            payload => format!(
+                "{{ {}: {}; {}: {} }}",
+                property_key(tag),
+                name,
+                property_key(content),
+                render_untagged_variant(payload)
+            ),
+        },
+        EnumRepresentation::Untagged => render_untagged_variant(&variant.payload),
+    }
+}
+
+fn render_untagged_variant(payload: &VariantPayload) -> String {
+    match payload {
+        VariantPayload::Unit => "null".to_string(),
+        VariantPayload::Newtype(ty) => render_wire_type(ty),
+        VariantPayload::Tuple(items) => render_wire_type(&WireType::Tuple(items.clone())),
+        VariantPayload::Struct(object) => render_object(object),
+        VariantPayload::Unknown => "unknown".to_string(),
+    }
+}
+
+/// Quote object keys that are not valid identifiers (e.g. kebab-case)
+fn property_key(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if is_identifier {
+        name.to_string()
+    } else {
+        string_literal(name)
+    }
+}
+
+fn string_literal(value: &str) -> String {
+    format!("{value:?}")
+}
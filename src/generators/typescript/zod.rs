@@ -0,0 +1,423 @@
+use crate::{
+    RouteInfo,
+    config::Config,
+    generators::{CodeGenerator, typescript::TypeImportManager},
+    mapping::{
+        EnumRepresentation, ValidationRule, VariantPayload, WireDefinition, WireField, WireObject,
+        WireShape, WireType, WireVariant, map_rust_type,
+    },
+};
+use std::collections::{BTreeMap, BTreeSet};
+use ts_quote::ts_string;
+
+/// Generates `schemas.ts` with Zod schemas mirroring the serde shape and
+/// `validator` rules of request body and response DTOs
+pub struct ZodSchemaGenerator;
+
+impl CodeGenerator for ZodSchemaGenerator {
+    type Config = Config;
+    type Output = String;
+
+    fn generate(
+        routes: &[RouteInfo],
+        config: &Self::Config,
+    ) -> Result<Self::Output, Box<dyn std::error::Error>> {
+        let registry = crate::parser::scan_type_definitions(config)?;
+        let roots = schema_roots(routes);
+        let (definitions, unresolved) = registry.resolve(roots.iter().map(String::as_str));
+
+        // Types without a DTO definition still get a schema so the client can
+        // reference it unconditionally; builtins like `String` map to primitives
+        let mut schemas: Vec<String> = unresolved
+            .iter()
+            .map(|type_name| {
+                let schema_name = schema_name(type_name);
+                let schema =
+                    match syn::parse_str::<syn::Type>(type_name).map(|ty| map_rust_type(&ty)) {
+                        Ok(WireType::Reference(_)) | Err(_) => "z.unknown()".to_string(),
+                        Ok(wire_type) => render_zod_type(&wire_type),
+                    };
+                ts_string! {
+                    export const #schema_name = #schema;
+                }
+                .to_string()
+            })
+            .collect();
+
+        // Schemas referring to themselves cannot be inferred and are typed with their DTO
+        let recursive = recursive_definitions(&definitions);
+        let mut type_manager = TypeImportManager::for_config(&config.typescript);
+        for name in &recursive {
+            type_manager.extract_importable_types(name);
+        }
+        let type_imports = type_manager.generate_imports().join("\n");
+        schemas.extend(definitions.iter().map(|definition| {
+            generate_zod_schema(definition, recursive.contains(&definition.name))
+        }));
+
+        // Array responses (`Vec<T>` in the handler) wrap the item schema
+        let array_items: BTreeSet<&str> = routes
+            .iter()
+            .filter_map(|route| route.handler_info.return_type.found_type.as_deref())
+            .filter_map(array_item)
+            .collect();
+        for item in array_items {
+            let array_schema_name = response_schema_name(&format!("Array<{item}>"));
+            let item_schema_name = schema_name(item);
+            schemas.push(
+                ts_string! {
+                    export const #array_schema_name = z.array(z.lazy(() => #item_schema_name));
+                }
+                .to_string(),
+            );
+        }
+
+        let schemas_str = schemas.join("\n");
+
+        let ts_code = ts_string! {
+            import { z } from "zod";
+            #type_imports
+
+            #schemas_str
+        };
+
+        let formatted = super::format_ts_code(
+            "schemas.ts",
+            &ts_code.to_string(),
+            &config.typescript.format,
+        )?;
+        Ok(formatted)
+    }
+}
+
+/// Name of the exported schema constant for a DTO
+pub fn schema_name(type_name: &str) -> String {
+    format!("{type_name}Schema")
+}
+
+/// Name of the schema constant validating a handler return type such as `Array<User>`
+pub fn response_schema_name(return_type: &str) -> String {
+    match array_item(return_type) {
+        Some(item) => format!("{item}ArraySchema"),
+        None => schema_name(return_type),
+    }
+}
+
+fn array_item(return_type: &str) -> Option<&str> {
+    return_type.strip_prefix("Array<")?.strip_suffix('>')
+}
+
+/// Types that get a top-level schema: every request body and response DTO
+pub fn schema_roots(routes: &[RouteInfo]) -> BTreeSet<String> {
+    let mut roots = BTreeSet::new();
+    for route in routes {
+        if let Some(body_type) = &route.handler_info.body_param {
+            roots.insert(body_type.clone());
+        }
+        if let Some(return_type) = &route.handler_info.return_type.found_type {
+            roots.insert(array_item(return_type).unwrap_or(return_type).to_string());
+        }
+    }
+    roots
+}
+
+/// Schema constants the client references for request bodies and responses
+pub fn client_schema_names(routes: &[RouteInfo]) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    for route in routes {
+        if let Some(body_type) = &route.handler_info.body_param
+            && route.method != "GET"
+        {
+            names.insert(schema_name(body_type));
+        }
+        if let Some(return_type) = &route.handler_info.return_type.found_type {
+            names.insert(response_schema_name(return_type));
+        }
+    }
+    names
+}
+
+/// Names of the definitions that refer back to themselves, directly or through others
+fn recursive_definitions(definitions: &[&WireDefinition]) -> BTreeSet<String> {
+    let references: BTreeMap<&str, BTreeSet<String>> = definitions
+        .iter()
+        .map(|definition| (definition.name.as_str(), definition.references()))
+        .collect();
+
+    let mut recursive = BTreeSet::new();
+    for definition in definitions {
+        let mut seen = BTreeSet::new();
+        let mut pending: Vec<&String> = references[definition.name.as_str()].iter().collect();
+        while let Some(name) = pending.pop() {
+            if *name == definition.name {
+                recursive.insert(definition.name.clone());
+                break;
+            }
+            if seen.insert(name)
+                && let Some(next) = references.get(name.as_str())
+            {
+                pending.extend(next);
+            }
+        }
+    }
+    recursive
+}
+
+/// Render a DTO definition as an exported Zod schema. Recursive schemas are annotated
+/// with the DTO type, which must be imported; the rest keep their inferred type
+pub fn generate_zod_schema(definition: &WireDefinition, recursive: bool) -> String {
+    let schema_name = schema_name(&definition.name);
+    let body = match &definition.shape {
+        WireShape::Object(object) => render_zod_object(object),
+        WireShape::Alias(ty) => render_zod_type(ty),
+        WireShape::Enum {
+            representation,
+            variants,
+        } => render_zod_enum(representation, variants),
+    };
+
+    if recursive {
+        let schema_type = format!("z.ZodType<{}>", definition.name);
+        return ts_string! {
+            export const #schema_name: #schema_type = #body;
+        }
+        .to_string();
+    }
+    ts_string! {
+        export const #schema_name = #body;
+    }
+    .to_string()
+}
+
+/// JavaScript string literal, escaped like JSON
+fn js_string(value: &str) -> String {
+    serde_json::to_string(value).expect("strings always serialize")
+}
+
+fn render_zod_type(ty: &WireType) -> String {
+    match ty {
+        WireType::String => "z.string()".to_string(),
+        WireType::Number => "z.number()".to_string(),
+        WireType::Boolean => "z.boolean()".to_string(),
+        WireType::Null => "z.null()".to_string(),
+        WireType::Unknown => "z.unknown()".to_string(),
+        WireType::Nullable(inner) => format!("{}.nullable()", render_zod_type(inner)),
+        WireType::Array(inner) => format!("z.array({})", render_zod_type(inner)),
+        WireType::Tuple(items) => format!(
+            "z.tuple([{}])",
+            items
+                .iter()
+                .map(render_zod_type)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        WireType::Record(value) => format!("z.record(z.string(), {})", render_zod_type(value)),
+        // Lazy references keep declaration order irrelevant and allow recursive DTOs
+        WireType::Reference(name) => format!("z.lazy(() => {})", schema_name(name)),
+    }
+}
+
+fn render_zod_object(object: &WireObject) -> String {
+    let fields: Vec<String> = object.fields.iter().map(render_zod_field).collect();
+    let mut schema = format!("z.object({{ {} }})", fields.join(" "));
+
+    // Cross-field rules need the whole object
+    for field in &object.fields {
+        for validation in &field.validations {
+            if let ValidationRule::MustMatch(other) = &validation.rule {
+                let other_name = object
+                    .fields
+                    .iter()
+                    .find(|candidate| &candidate.rust_name == other)
+                    .map(|candidate| candidate.name.as_str())
+                    .unwrap_or(other);
+                let message = validation
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| format!("must match {other_name}"));
+                schema.push_str(&format!(
+                    ".refine((data) => data[{}] === data[{}], {{ message: {}, path: [{}] }})",
+                    js_string(&field.name),
+                    js_string(other_name),
+                    js_string(&message),
+                    js_string(&field.name)
+                ));
+            }
+        }
+    }
+
+    for flattened in &object.flatten {
+        schema.push_str(&format!(".and({})", render_zod_type(flattened)));
+    }
+
+    schema
+}
+
+fn render_zod_field(field: &WireField) -> String {
+    let (base, nullable) = match &field.ty {
+        WireType::Nullable(inner) => (inner.as_ref(), true),
+        ty => (ty, false),
+    };
+    let required = field
+        .validations
+        .iter()
+        .any(|validation| validation.rule == ValidationRule::Required);
+
+    let mut schema = render_zod_type(base);
+    for validation in &field.validations {
+        let message = validation
+            .message
+            .as_ref()
+            .map(|message| format!(", {{ message: {} }}", js_string(message)))
+            .unwrap_or_default();
+        schema.push_str(&render_zod_rule(&validation.rule, base, &message));
+    }
+
+    if nullable && !required {
+        schema.push_str(".nullable()");
+    }
+    if field.optional && !required {
+        schema.push_str(".optional()");
+    }
+
+    format!("{}: {},", js_string(&field.name), schema)
+}
+
+/// Zod refinements for one validator rule; rules that do not apply to the type are dropped
+fn render_zod_rule(rule: &ValidationRule, ty: &WireType, message: &str) -> String {
+    let is_string = matches!(ty, WireType::String);
+    let is_sized = is_string || matches!(ty, WireType::Array(_));
+
+    match rule {
+        ValidationRule::Length { min, max, equal } if is_sized => {
+            let mut checks = String::new();
+            if let Some(min) = min {
+                checks.push_str(&format!(".min({min}{message})"));
+            }
+            if let Some(max) = max {
+                checks.push_str(&format!(".max({max}{message})"));
+            }
+            if let Some(equal) = equal {
+                checks.push_str(&format!(".length({equal}{message})"));
+            }
+            checks
+        }
+        ValidationRule::Range {
+            min,
+            max,
+            exclusive_min,
+            exclusive_max,
+        } if matches!(ty, WireType::Number) => {
+            let mut checks = String::new();
+            if let Some(min) = min {
+                checks.push_str(&format!(".gte({min}{message})"));
+            }
+            if let Some(max) = max {
+                checks.push_str(&format!(".lte({max}{message})"));
+            }
+            if let Some(min) = exclusive_min {
+                checks.push_str(&format!(".gt({min}{message})"));
+            }
+            if let Some(max) = exclusive_max {
+                checks.push_str(&format!(".lt({max}{message})"));
+            }
+            checks
+        }
+        ValidationRule::Email if is_string => {
+            format!(".email({})", message.trim_start_matches(", "))
+        }
+        ValidationRule::Url if is_string => format!(".url({})", message.trim_start_matches(", ")),
+        ValidationRule::Contains(pattern) if is_string => {
+            format!(".includes({}{message})", js_string(pattern))
+        }
+        ValidationRule::DoesNotContain(pattern) if is_string => {
+            let message = if message.is_empty() {
+                format!(
+                    ", {{ message: {} }}",
+                    js_string(&format!("must not contain {pattern}"))
+                )
+            } else {
+                message.to_string()
+            };
+            format!(
+                ".refine((value) => !value.includes({}){message})",
+                js_string(pattern)
+            )
+        }
+        ValidationRule::NonControlCharacter if is_string => {
+            format!(".regex(/^[^\\p{{Cc}}]*$/u{message})")
+        }
+        _ => String::new(),
+    }
+}
+
+fn render_zod_enum(representation: &EnumRepresentation, variants: &[WireVariant]) -> String {
+    let options: Vec<String> = variants
+        .iter()
+        .map(|variant| {
+            if variant.untagged {
+                render_zod_payload(&variant.payload)
+            } else {
+                render_zod_variant(representation, variant)
+            }
+        })
+        .collect();
+
+    match options.as_slice() {
+        [] => "z.never()".to_string(),
+        [single] => single.clone(),
+        _ => format!("z.union([{}])", options.join(", ")),
+    }
+}
+
+fn render_zod_variant(representation: &EnumRepresentation, variant: &WireVariant) -> String {
+    // See `VariantPayload::Unknown`
+    if variant.payload == VariantPayload::Unknown {
+        return "z.unknown()".to_string();
+    }
+    let literal = format!("z.literal({})", js_string(&variant.name));
+
+    match representation {
+        EnumRepresentation::External => match &variant.payload {
+            VariantPayload::Unit => literal,
+            payload => format!(
+                "z.object({{ {}: {} }})",
+                js_string(&variant.name),
+                render_zod_payload(payload)
+            ),
+        },
+        EnumRepresentation::Internal { tag } => {
+            let tag_object = format!("z.object({{ {}: {literal} }})", js_string(tag));
+            match &variant.payload {
+                VariantPayload::Unit => tag_object,
+                VariantPayload::Newtype(ty) => format!("{tag_object}.and({})", render_zod_type(ty)),
+                VariantPayload::Struct(object) => {
+                    format!("{tag_object}.and({})", render_zod_object(object))
+                }
+                VariantPayload::Tuple(_) | VariantPayload::Unknown => {
+                    unreachable!("internally tagged tuple variants are mapped as unknown")
+                }
+            }
+        }
+        EnumRepresentation::Adjacent { tag, content } => match &variant.payload {
+            VariantPayload::Unit => format!("z.object({{ {}: {literal} }})", js_string(tag)),
+            payload => format!(
+                "z.object({{ {}: {literal}, {}: {} }})",
+                js_string(tag),
+                js_string(content),
+                render_zod_payload(payload)
+            ),
+        },
+        EnumRepresentation::Untagged => render_zod_payload(&variant.payload),
+    }
+}
+
+fn render_zod_payload(payload: &VariantPayload) -> String {
+    match payload {
+        VariantPayload::Unit => "z.null()".to_string(),
+        VariantPayload::Newtype(ty) => render_zod_type(ty),
+        VariantPayload::Tuple(items) => render_zod_type(&WireType::Tuple(items.clone())),
+        VariantPayload::Struct(object) => render_zod_object(object),
+        VariantPayload::Unknown => "z.unknown()".to_string(),
+    }
+}
@@ -1,44 +1,134 @@
 mod config;
 mod generators;
+mod mapping;
+mod output;
 mod parser;
 mod utils;
 use std::path::PathBuf;
 
 use crate::generators::CodeGenerator;
-pub use config::{Config, NamingConfig, TypeScriptConfig};
+pub use config::{
+    CaseStyle, CollisionStrategy, Config, GroupBy, NamingConfig, NamingStrategy, QuoteStyle,
+    RouteOrder, RustClientConfig, RustConfig, RustLayout, RustTestHelpersConfig, TemplateEngine,
+    TypeScriptConfig, TypeScriptFormatConfig,
+};
 pub use generators::{
-    rust::RustLinksGenerator,
-    typescript::{TypeScriptClientGenerator, TypeScriptHooksGenerator},
+    rust::{RustClientGenerator, RustLinksGenerator, RustTestLinksGenerator},
+    typescript::{
+        TypeScriptClientGenerator, TypeScriptHooksGenerator, TypeScriptTypesGenerator,
+        ZodSchemaGenerator,
+    },
+};
+pub use mapping::{
+    EnumRepresentation, TypeRegistry, Validation, ValidationRule, VariantPayload, WireDefinition,
+    WireField, WireObject, WireShape, WireType, WireVariant,
 };
-pub use parser::{HandlerInfo, RouteInfo, scan_controllers_folder};
-pub use utils::{case, path};
+pub use output::{GeneratedFile, GenerationResult, WriteStatus, check_outputs, write_if_changed};
+pub use parser::{HandlerInfo, RouteInfo, scan_controllers_folder, scan_type_definitions};
+pub use utils::{case, hash, path};
 
 /// Main function to generate links enum from controller files
-pub fn generate_links(config: &Config) -> Result<String, Box<dyn std::error::Error>> {
+pub fn generate_links(config: &Config) -> Result<GenerationResult, Box<dyn std::error::Error>> {
+    run_generation(config, config.check.unwrap_or(false))
+}
+
+/// Verify that generated files on disk are up to date without writing anything,
+/// e.g. from CI. Fails with a unified diff for every stale file.
+pub fn check_links(config: &Config) -> Result<GenerationResult, Box<dyn std::error::Error>> {
+    run_generation(config, true)
+}
+
+fn run_generation(
+    config: &Config,
+    check: bool,
+) -> Result<GenerationResult, Box<dyn std::error::Error>> {
     let routes = scan_controllers_folder(config)?;
 
-    // Generate Rust links enum
-    let rust_code = RustLinksGenerator::generate(&routes, config)?;
+    let mut outputs = RustLinksGenerator::generate_files(&routes, config)?;
+    // Reuse the written links enum, rendering it separately only when no file holds it as is
+    let rust_code = match RustLinksGenerator::single_file_code(&outputs, config) {
+        Some(code) => code,
+        None => RustLinksGenerator::generate(&routes, config)?,
+    };
+    let rust_file_count = outputs.len();
+    outputs.extend(typescript_outputs(&routes, config)?);
+    let typescript_files = rust_file_count..outputs.len();
+    outputs.extend(RustClientGenerator::generate_files(&routes, config)?);
+    outputs.extend(RustTestLinksGenerator::generate_files(&routes, config)?);
+    if check {
+        output::check_outputs(&outputs)?;
+        let files = outputs
+            .into_iter()
+            .map(|(path, _)| GeneratedFile {
+                path,
+                status: WriteStatus::Checked,
+            })
+            .collect();
+        return Ok(GenerationResult { rust_code, files });
+    }
 
-    // Generate TypeScript client if requested
-    if config.typescript.generate_client.unwrap_or(false)
-        && let Some(ts_output) = &config.typescript.output_path
+    // Only touch files whose content changed so dev servers and Cargo don't reload
+    let mut files = Vec::new();
+    for (path, content) in outputs {
+        let status = output::write_if_changed(&path, &content)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        files.push(GeneratedFile { path, status });
+    }
+
+    let result = GenerationResult { rust_code, files };
+    if let Some(rust_file) = result.files.first()
+        && result.files[..rust_file_count]
+            .iter()
+            .any(|file| file.status != WriteStatus::Unchanged)
+    {
+        println!(
+            "cargo:warning=Generated Rust links enum at: {}",
+            rust_file.path.display()
+        );
+    }
+    if let Some(ts_output) = &config.typescript.output_path
+        && result.files[typescript_files]
+            .iter()
+            .any(|file| file.status != WriteStatus::Unchanged)
     {
-        let ts_client_code = TypeScriptClientGenerator::generate(&routes, &config.typescript)?;
-        let ts_hooks_code = TypeScriptHooksGenerator::generate(&routes, &config.typescript)?;
-
-        // Combine client and hooks
-        let ts_client_code_path = PathBuf::from(ts_output).join("client.ts");
-        let ts_hooks_code_path = PathBuf::from(ts_output).join("api.ts");
-        std::fs::write(ts_client_code_path, ts_client_code)?;
-        std::fs::write(ts_hooks_code_path, ts_hooks_code)?;
         println!(
             "cargo:warning=Generated TypeScript client at: {}",
             ts_output.display()
         );
     }
 
-    Ok(rust_code)
+    Ok(result)
+}
+
+/// Generate the TypeScript files requested by the config, paired with their destination
+fn typescript_outputs(
+    routes: &[RouteInfo],
+    config: &Config,
+) -> Result<Vec<(PathBuf, String)>, Box<dyn std::error::Error>> {
+    let mut outputs = Vec::new();
+
+    if config.typescript.generate_client.unwrap_or(false)
+        && let Some(ts_output) = &config.typescript.output_path
+    {
+        let ts_client_code = TypeScriptClientGenerator::generate(routes, &config.typescript)?;
+        let ts_hooks_code = TypeScriptHooksGenerator::generate(routes, &config.typescript)?;
+        outputs.push((ts_output.join("client.ts"), ts_client_code));
+        outputs.push((ts_output.join("api.ts"), ts_hooks_code));
+
+        // Serde-aware DTO definitions imported by the client and hooks
+        if config.typescript.generate_types.unwrap_or(false) {
+            let ts_types_code = TypeScriptTypesGenerator::generate(routes, config)?;
+            outputs.push((ts_output.join("types.ts"), ts_types_code));
+        }
+
+        // Zod schemas used for request validation
+        if config.typescript.generate_zod_schemas.unwrap_or(false) {
+            let ts_schemas_code = ZodSchemaGenerator::generate(routes, config)?;
+            outputs.push((ts_output.join("schemas.ts"), ts_schemas_code));
+        }
+    }
+
+    Ok(outputs)
 }
 
 /// Generate TypeScript HTTP client compatible with tanstack-query
@@ -54,3 +144,31 @@ pub fn generate_ts_hooks(config: &Config) -> Result<String, Box<dyn std::error::
     let ts_code = TypeScriptHooksGenerator::generate(&routes, &config.typescript)?;
     Ok(ts_code)
 }
+
+/// Generate TypeScript DTO definitions matching the serde wire format
+pub fn generate_ts_types(config: &Config) -> Result<String, Box<dyn std::error::Error>> {
+    let routes = scan_controllers_folder(config)?;
+    let ts_code = TypeScriptTypesGenerator::generate(&routes, config)?;
+    Ok(ts_code)
+}
+
+/// Generate the typed `reqwest` client for calling the API from Rust
+pub fn generate_rust_client(config: &Config) -> Result<String, Box<dyn std::error::Error>> {
+    let routes = scan_controllers_folder(config)?;
+    let rust_code = RustClientGenerator::generate(&routes, config)?;
+    Ok(rust_code)
+}
+
+/// Generate the `TestLinks` request helpers for integration tests
+pub fn generate_rust_test_links(config: &Config) -> Result<String, Box<dyn std::error::Error>> {
+    let routes = scan_controllers_folder(config)?;
+    let rust_code = RustTestLinksGenerator::generate(&routes, config)?;
+    Ok(rust_code)
+}
+
+/// Generate Zod schemas for request body DTOs
+pub fn generate_zod_schemas(config: &Config) -> Result<String, Box<dyn std::error::Error>> {
+    let routes = scan_controllers_folder(config)?;
+    let ts_code = ZodSchemaGenerator::generate(&routes, config)?;
+    Ok(ts_code)
+}
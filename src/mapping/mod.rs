@@ -0,0 +1,786 @@
+mod rust_types;
+mod serde_attrs;
+mod validator_attrs;
+
+pub use rust_types::map_rust_type;
+pub use serde_attrs::RenameRule;
+pub use validator_attrs::{Validation, ValidationRule};
+
+use serde_attrs::{ContainerAttrs, FieldAttrs, VariantAttrs};
+use std::collections::{BTreeMap, BTreeSet};
+use syn::ext::IdentExt;
+
+/// JSON value shape of a Rust type as produced by serde
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WireType {
+    String,
+    Number,
+    Boolean,
+    Null,
+    Unknown,
+    Nullable(Box<WireType>),
+    Array(Box<WireType>),
+    Tuple(Vec<WireType>),
+    Record(Box<WireType>),
+    Reference(String),
+}
+
+/// A single JSON object key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WireField {
+    /// Key as it appears in the JSON
+    pub name: String,
+    /// Field name in the Rust source
+    pub rust_name: String,
+    pub ty: WireType,
+    /// Whether the key may be missing from the JSON
+    pub optional: bool,
+    /// Rules from `#[validate(...)]` attributes
+    pub validations: Vec<Validation>,
+}
+
+/// A JSON object, including the types flattened into it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct WireObject {
+    pub fields: Vec<WireField>,
+    pub flatten: Vec<WireType>,
+}
+
+/// How serde represents an enum on the wire
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EnumRepresentation {
+    External,
+    Internal { tag: String },
+    Adjacent { tag: String, content: String },
+    Untagged,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VariantPayload {
+    Unit,
+    Newtype(WireType),
+    Tuple(Vec<WireType>),
+    Struct(WireObject),
+    /// A payload serde cannot represent this way, e.g. a tuple variant of an
+    /// internally tagged enum; the whole variant is typed as unknown
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WireVariant {
+    /// Variant name as it appears in the JSON
+    pub name: String,
+    /// Set by `#[serde(untagged)]` on the variant itself
+    pub untagged: bool,
+    pub payload: VariantPayload,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WireShape {
+    Object(WireObject),
+    Alias(WireType),
+    Enum {
+        representation: EnumRepresentation,
+        variants: Vec<WireVariant>,
+    },
+}
+
+/// A DTO definition mapped to its exact JSON shape
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WireDefinition {
+    pub name: String,
+    pub shape: WireShape,
+}
+
+/// All DTO definitions found while scanning, keyed by type name
+#[derive(Debug, Clone, Default)]
+pub struct TypeRegistry {
+    definitions: BTreeMap<String, WireDefinition>,
+}
+
+impl WireType {
+    /// Collect the names of all types referenced by this type
+    pub fn collect_references(&self, references: &mut BTreeSet<String>) {
+        match self {
+            WireType::Reference(name) => {
+                references.insert(name.clone());
+            }
+            WireType::Nullable(inner) | WireType::Array(inner) | WireType::Record(inner) => {
+                inner.collect_references(references)
+            }
+            WireType::Tuple(items) => items
+                .iter()
+                .for_each(|item| item.collect_references(references)),
+            _ => {}
+        }
+    }
+
+    /// Replace references to generic parameters, which we cannot resolve, with unknown
+    fn erase_generics(&mut self, params: &[String]) {
+        match self {
+            WireType::Reference(name) if params.contains(name) => *self = WireType::Unknown,
+            WireType::Nullable(inner) | WireType::Array(inner) | WireType::Record(inner) => {
+                inner.erase_generics(params)
+            }
+            WireType::Tuple(items) => items
+                .iter_mut()
+                .for_each(|item| item.erase_generics(params)),
+            _ => {}
+        }
+    }
+}
+
+impl WireObject {
+    fn types_mut(&mut self) -> impl Iterator<Item = &mut WireType> {
+        self.fields
+            .iter_mut()
+            .map(|field| &mut field.ty)
+            .chain(self.flatten.iter_mut())
+    }
+
+    fn types(&self) -> impl Iterator<Item = &WireType> {
+        self.fields
+            .iter()
+            .map(|field| &field.ty)
+            .chain(self.flatten.iter())
+    }
+}
+
+impl WireDefinition {
+    /// Names of all types this definition refers to
+    pub fn references(&self) -> BTreeSet<String> {
+        let mut references = BTreeSet::new();
+        for ty in self.types() {
+            ty.collect_references(&mut references);
+        }
+        references
+    }
+
+    fn types(&self) -> Vec<&WireType> {
+        match &self.shape {
+            WireShape::Object(object) => object.types().collect(),
+            WireShape::Alias(ty) => vec![ty],
+            WireShape::Enum { variants, .. } => variants
+                .iter()
+                .flat_map(|variant| match &variant.payload {
+                    VariantPayload::Unit | VariantPayload::Unknown => Vec::new(),
+                    VariantPayload::Newtype(ty) => vec![ty],
+                    VariantPayload::Tuple(items) => items.iter().collect(),
+                    VariantPayload::Struct(object) => object.types().collect(),
+                })
+                .collect(),
+        }
+    }
+
+    fn types_mut(&mut self) -> Vec<&mut WireType> {
+        match &mut self.shape {
+            WireShape::Object(object) => object.types_mut().collect(),
+            WireShape::Alias(ty) => vec![ty],
+            WireShape::Enum { variants, .. } => variants
+                .iter_mut()
+                .flat_map(|variant| match &mut variant.payload {
+                    VariantPayload::Unit | VariantPayload::Unknown => Vec::new(),
+                    VariantPayload::Newtype(ty) => vec![ty],
+                    VariantPayload::Tuple(items) => items.iter_mut().collect(),
+                    VariantPayload::Struct(object) => object.types_mut().collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TypeRegistry {
+    pub fn insert(&mut self, definition: WireDefinition) {
+        if self.definitions.contains_key(&definition.name) {
+            println!(
+                "cargo:warning=Duplicate DTO definition '{}' skipped",
+                definition.name
+            );
+            return;
+        }
+        self.definitions.insert(definition.name.clone(), definition);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&WireDefinition> {
+        self.definitions.get(name)
+    }
+
+    pub fn definitions(&self) -> impl Iterator<Item = &WireDefinition> {
+        self.definitions.values()
+    }
+
+    /// Definitions reachable from `roots`, sorted by name, along with the
+    /// referenced names that are not defined in the registry
+    pub fn resolve<'a>(
+        &self,
+        roots: impl IntoIterator<Item = &'a str>,
+    ) -> (Vec<&WireDefinition>, BTreeSet<String>) {
+        let mut resolved = BTreeMap::new();
+        let mut unresolved = BTreeSet::new();
+        let mut pending: Vec<String> = roots.into_iter().map(str::to_string).collect();
+
+        while let Some(name) = pending.pop() {
+            if resolved.contains_key(&name) || unresolved.contains(&name) {
+                continue;
+            }
+            match self.definitions.get(&name) {
+                Some(definition) => {
+                    pending.extend(definition.references());
+                    resolved.insert(name, definition);
+                }
+                None => {
+                    unresolved.insert(name);
+                }
+            }
+        }
+
+        (resolved.into_values().collect(), unresolved)
+    }
+}
+
+/// Whether an item derives `Serialize` or `Deserialize`
+pub fn derives_serde(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .any(|attr| {
+            let mut found = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|s| s.ident == "Serialize" || s.ident == "Deserialize")
+                {
+                    found = true;
+                }
+                Ok(())
+            });
+            found
+        })
+}
+
+/// Map a struct or enum deriving serde traits to its JSON shape
+pub fn map_item(item: &syn::Item) -> syn::Result<Option<WireDefinition>> {
+    let (name, generics, shape) = match item {
+        syn::Item::Struct(item) if derives_serde(&item.attrs) => {
+            let name = item.ident.to_string();
+            (name.clone(), &item.generics, map_struct(&name, item)?)
+        }
+        syn::Item::Enum(item) if derives_serde(&item.attrs) => {
+            let name = item.ident.to_string();
+            (name.clone(), &item.generics, map_enum(&name, item)?)
+        }
+        _ => return Ok(None),
+    };
+
+    let mut definition = WireDefinition { name, shape };
+
+    let params: Vec<String> = generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect();
+    if !params.is_empty() {
+        warn(
+            &definition.name,
+            "generic parameters cannot be modelled and are mapped as unknown",
+        );
+        for ty in definition.types_mut() {
+            ty.erase_generics(&params);
+        }
+    }
+
+    Ok(Some(definition))
+}
+
+fn map_struct(name: &str, item: &syn::ItemStruct) -> syn::Result<WireShape> {
+    let container = ContainerAttrs::parse(&item.attrs)?;
+    warn_unsupported(name, &container.unsupported);
+
+    if let Some(into) = container.into {
+        return Ok(WireShape::Alias(WireType::Reference(into)));
+    }
+
+    let shape = match &item.fields {
+        syn::Fields::Named(fields) if container.transparent => {
+            let mut shape = WireShape::Alias(WireType::Unknown);
+            for field in &fields.named {
+                if !FieldAttrs::parse(&field.attrs)?.is_skipped() {
+                    shape = WireShape::Alias(map_rust_type(&field.ty));
+                    break;
+                }
+            }
+            shape
+        }
+        syn::Fields::Named(fields) => WireShape::Object(map_named_fields(
+            name,
+            &fields.named,
+            container.rename_all,
+            container.default,
+        )?),
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            WireShape::Alias(map_rust_type(&fields.unnamed[0].ty))
+        }
+        syn::Fields::Unnamed(fields) => WireShape::Alias(WireType::Tuple(
+            fields
+                .unnamed
+                .iter()
+                .map(|f| map_rust_type(&f.ty))
+                .collect(),
+        )),
+        syn::Fields::Unit => WireShape::Alias(WireType::Null),
+    };
+
+    Ok(shape)
+}
+
+fn map_enum(name: &str, item: &syn::ItemEnum) -> syn::Result<WireShape> {
+    let container = ContainerAttrs::parse(&item.attrs)?;
+    warn_unsupported(name, &container.unsupported);
+
+    if let Some(into) = container.into {
+        return Ok(WireShape::Alias(WireType::Reference(into)));
+    }
+
+    let representation = match (container.untagged, container.tag, container.content) {
+        (true, _, _) => EnumRepresentation::Untagged,
+        (false, Some(tag), Some(content)) => EnumRepresentation::Adjacent { tag, content },
+        (false, Some(tag), None) => EnumRepresentation::Internal { tag },
+        (false, None, _) => EnumRepresentation::External,
+    };
+
+    let mut variants = Vec::new();
+    for variant in &item.variants {
+        let attrs = VariantAttrs::parse(&variant.attrs)?;
+        let variant_path = format!("{}::{}", name, variant.ident);
+        warn_unsupported(&variant_path, &attrs.unsupported);
+
+        if attrs.skip {
+            continue;
+        }
+
+        let variant_name = variant.ident.unraw().to_string();
+        let wire_name = attrs.rename.unwrap_or_else(|| match container.rename_all {
+            Some(rule) => rule.apply_to_variant(&variant_name),
+            None => variant_name,
+        });
+
+        let payload = match &variant.fields {
+            syn::Fields::Unit => VariantPayload::Unit,
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                VariantPayload::Newtype(map_rust_type(&fields.unnamed[0].ty))
+            }
+            syn::Fields::Unnamed(_)
+                if matches!(representation, EnumRepresentation::Internal { .. }) =>
+            {
+                warn(
+                    &variant_path,
+                    "tuple variants cannot be internally tagged and are mapped as unknown",
+                );
+                VariantPayload::Unknown
+            }
+            syn::Fields::Unnamed(fields) => VariantPayload::Tuple(
+                fields
+                    .unnamed
+                    .iter()
+                    .map(|f| map_rust_type(&f.ty))
+                    .collect(),
+            ),
+            syn::Fields::Named(fields) => VariantPayload::Struct(map_named_fields(
+                &variant_path,
+                &fields.named,
+                attrs.rename_all.or(container.rename_all_fields),
+                false,
+            )?),
+        };
+
+        variants.push(WireVariant {
+            name: wire_name,
+            untagged: attrs.untagged,
+            payload,
+        });
+    }
+
+    Ok(WireShape::Enum {
+        representation,
+        variants,
+    })
+}
+
+fn map_named_fields<'a>(
+    owner: &str,
+    fields: impl IntoIterator<Item = &'a syn::Field>,
+    rename_all: Option<RenameRule>,
+    container_default: bool,
+) -> syn::Result<WireObject> {
+    let mut object = WireObject::default();
+
+    for field in fields {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+        let rust_name = ident.unraw().to_string();
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+
+        if attrs.is_skipped() {
+            continue;
+        }
+
+        let field_path = format!("{owner}.{rust_name}");
+        let (custom, unsupported): (Vec<_>, Vec<_>) =
+            attrs.unsupported.iter().cloned().partition(|key| {
+                matches!(key.as_str(), "with" | "serialize_with" | "deserialize_with")
+            });
+        warn_unsupported(&field_path, &unsupported);
+        let custom_serializer = !custom.is_empty();
+        if custom_serializer {
+            warn(
+                &field_path,
+                &format!(
+                    "#[serde({})] uses a custom serializer and is mapped as unknown",
+                    custom[0]
+                ),
+            );
+        }
+        let ty = if custom_serializer {
+            WireType::Unknown
+        } else {
+            map_rust_type(&field.ty)
+        };
+
+        if attrs.flatten {
+            object.flatten.push(match ty {
+                WireType::Nullable(inner) => *inner,
+                ty => ty,
+            });
+            continue;
+        }
+
+        let optional = (matches!(ty, WireType::Nullable(_)) && !custom_serializer)
+            || attrs.default
+            || container_default
+            || attrs.skip_serializing_if
+            || attrs.skip_serializing
+            || attrs.skip_deserializing;
+
+        let name = attrs.rename.unwrap_or_else(|| match rename_all {
+            Some(rule) => rule.apply_to_field(&rust_name),
+            None => rust_name.clone(),
+        });
+
+        let (validations, unsupported_rules) = validator_attrs::parse_validations(&field.attrs)?;
+        for rule in unsupported_rules {
+            warn(
+                &field_path,
+                &format!("#[validate({rule})] cannot be modelled and is ignored"),
+            );
+        }
+
+        object.fields.push(WireField {
+            name,
+            rust_name,
+            ty,
+            optional,
+            validations,
+        });
+    }
+
+    Ok(object)
+}
+
+fn warn_unsupported(owner: &str, keys: &[String]) {
+    for key in keys {
+        warn(
+            owner,
+            &format!("#[serde({key})] cannot be modelled and is ignored"),
+        );
+    }
+}
+
+fn warn(owner: &str, message: &str) {
+    println!("cargo:warning={owner}: {message}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn shape(item: syn::Item) -> WireShape {
+        map_item(&item).unwrap().unwrap().shape
+    }
+
+    fn object(item: syn::Item) -> WireObject {
+        match shape(item) {
+            WireShape::Object(object) => object,
+            other => panic!("expected an object, got {other:?}"),
+        }
+    }
+
+    fn enum_shape(item: syn::Item) -> (EnumRepresentation, Vec<WireVariant>) {
+        match shape(item) {
+            WireShape::Enum {
+                representation,
+                variants,
+            } => (representation, variants),
+            other => panic!("expected an enum, got {other:?}"),
+        }
+    }
+
+    fn field<'a>(object: &'a WireObject, name: &str) -> &'a WireField {
+        object
+            .fields
+            .iter()
+            .find(|field| field.name == name)
+            .unwrap_or_else(|| panic!("no field {name} in {object:?}"))
+    }
+
+    #[test]
+    fn items_without_serde_derives_are_ignored() {
+        let item: syn::Item = parse_quote! {
+            #[derive(Debug, Clone)]
+            struct Plain { id: i32 }
+        };
+        assert_eq!(map_item(&item).unwrap(), None);
+    }
+
+    #[test]
+    fn rename_all_and_rename_set_json_keys() {
+        let object = object(parse_quote! {
+            #[derive(Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct User {
+                user_id: i64,
+                #[serde(rename = "mail")]
+                email_address: String,
+                #[serde(skip)]
+                password_hash: String,
+                r#type: String,
+            }
+        });
+        let names: Vec<_> = object.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["userId", "mail", "type"]);
+        assert_eq!(field(&object, "userId").rust_name, "user_id");
+        assert_eq!(field(&object, "type").rust_name, "type");
+    }
+
+    #[test]
+    fn options_defaults_and_skip_serializing_if_are_optional() {
+        let object = object(parse_quote! {
+            #[derive(Deserialize)]
+            struct Filter {
+                name: String,
+                nickname: Option<String>,
+                #[serde(default)]
+                page: u32,
+                #[serde(skip_serializing_if = "Vec::is_empty")]
+                tags: Vec<String>,
+                #[serde(with = "custom")]
+                since: Option<String>,
+            }
+        });
+        assert!(!field(&object, "name").optional);
+        assert!(field(&object, "nickname").optional);
+        assert_eq!(
+            field(&object, "nickname").ty,
+            WireType::Nullable(Box::new(WireType::String))
+        );
+        assert!(field(&object, "page").optional);
+        assert!(field(&object, "tags").optional);
+        // Custom serializers may write anything, including a required value
+        assert_eq!(field(&object, "since").ty, WireType::Unknown);
+        assert!(!field(&object, "since").optional);
+    }
+
+    #[test]
+    fn flattened_fields_are_merged_by_reference() {
+        let object = object(parse_quote! {
+            #[derive(Serialize)]
+            struct Page {
+                #[serde(flatten)]
+                meta: Meta,
+                #[serde(flatten)]
+                extra: Option<Extra>,
+                items: Vec<Item>,
+            }
+        });
+        assert_eq!(
+            object.flatten,
+            [
+                WireType::Reference("Meta".to_string()),
+                WireType::Reference("Extra".to_string())
+            ]
+        );
+        assert_eq!(object.fields.len(), 1);
+    }
+
+    #[test]
+    fn externally_tagged_enums_rename_variants() {
+        let (representation, variants) = enum_shape(parse_quote! {
+            #[derive(Serialize)]
+            #[serde(rename_all = "snake_case")]
+            enum Event {
+                SignedUp,
+                #[serde(rename = "bye")]
+                LoggedOut,
+                Moved(String),
+                Resized(u32, u32),
+                #[serde(skip)]
+                Internal,
+            }
+        });
+        assert_eq!(representation, EnumRepresentation::External);
+        let names: Vec<_> = variants.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, ["signed_up", "bye", "moved", "resized"]);
+        assert_eq!(
+            variants[2].payload,
+            VariantPayload::Newtype(WireType::String)
+        );
+        assert_eq!(
+            variants[3].payload,
+            VariantPayload::Tuple(vec![WireType::Number, WireType::Number])
+        );
+    }
+
+    #[test]
+    fn internally_tagged_tuple_variants_are_unknown() {
+        let (representation, variants) = enum_shape(parse_quote! {
+            #[derive(Serialize)]
+            #[serde(tag = "kind")]
+            enum Shape {
+                Circle { radius: f64 },
+                Named(Name),
+                Point(f64, f64),
+            }
+        });
+        assert_eq!(
+            representation,
+            EnumRepresentation::Internal {
+                tag: "kind".to_string()
+            }
+        );
+        assert!(matches!(variants[0].payload, VariantPayload::Struct(_)));
+        assert_eq!(
+            variants[1].payload,
+            VariantPayload::Newtype(WireType::Reference("Name".to_string()))
+        );
+        assert_eq!(variants[2].payload, VariantPayload::Unknown);
+    }
+
+    #[test]
+    fn adjacent_and_untagged_representations() {
+        let (representation, _) = enum_shape(parse_quote! {
+            #[derive(Serialize)]
+            #[serde(tag = "t", content = "c")]
+            enum Message { Text(String) }
+        });
+        assert_eq!(
+            representation,
+            EnumRepresentation::Adjacent {
+                tag: "t".to_string(),
+                content: "c".to_string()
+            }
+        );
+
+        let (representation, _) = enum_shape(parse_quote! {
+            #[derive(Serialize)]
+            #[serde(untagged, tag = "ignored")]
+            enum Id { Number(u64), Text(String) }
+        });
+        assert_eq!(representation, EnumRepresentation::Untagged);
+
+        let (_, variants) = enum_shape(parse_quote! {
+            #[derive(Serialize)]
+            enum Value {
+                Known { id: u64 },
+                #[serde(untagged)]
+                Other(String),
+            }
+        });
+        assert!(!variants[0].untagged);
+        assert!(variants[1].untagged);
+    }
+
+    #[test]
+    fn rename_all_fields_applies_to_struct_variants() {
+        let (_, variants) = enum_shape(parse_quote! {
+            #[derive(Serialize)]
+            #[serde(rename_all_fields = "camelCase")]
+            enum Change {
+                Renamed { old_name: String },
+                #[serde(rename_all = "kebab-case")]
+                Moved { new_path: String },
+            }
+        });
+        let keys: Vec<_> = variants
+            .iter()
+            .map(|variant| match &variant.payload {
+                VariantPayload::Struct(object) => object.fields[0].name.clone(),
+                other => panic!("expected a struct variant, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(keys, ["oldName", "new-path"]);
+    }
+
+    #[test]
+    fn transparent_into_and_tuple_structs_are_aliases() {
+        assert_eq!(
+            shape(parse_quote! {
+                #[derive(Serialize)]
+                #[serde(transparent)]
+                struct Wrapper { #[serde(skip)] marker: (), inner: Vec<u8> }
+            }),
+            WireShape::Alias(WireType::Array(Box::new(WireType::Number)))
+        );
+        assert_eq!(
+            shape(parse_quote! {
+                #[derive(Serialize)]
+                #[serde(into = "dto::Public")]
+                struct Private { secret: String }
+            }),
+            WireShape::Alias(WireType::Reference("Public".to_string()))
+        );
+        assert_eq!(
+            shape(parse_quote! {
+                #[derive(Serialize)]
+                struct Pair(String, bool);
+            }),
+            WireShape::Alias(WireType::Tuple(vec![WireType::String, WireType::Boolean]))
+        );
+    }
+
+    #[test]
+    fn generic_parameters_are_erased() {
+        let object = object(parse_quote! {
+            #[derive(Serialize)]
+            struct Paged<T> { items: Vec<T>, next: Option<Cursor> }
+        });
+        assert_eq!(
+            field(&object, "items").ty,
+            WireType::Array(Box::new(WireType::Unknown))
+        );
+        assert_eq!(
+            field(&object, "next").ty,
+            WireType::Nullable(Box::new(WireType::Reference("Cursor".to_string())))
+        );
+    }
+
+    #[test]
+    fn registry_resolves_references_transitively() {
+        let mut registry = TypeRegistry::default();
+        for item in [
+            parse_quote! { #[derive(Serialize)] struct Order { customer: Customer } },
+            parse_quote! { #[derive(Serialize)] struct Customer { address: Address, tags: Vec<Tag> } },
+            parse_quote! { #[derive(Serialize)] struct Tag { name: String } },
+            parse_quote! { #[derive(Serialize)] struct Unused { name: String } },
+        ] {
+            let item: syn::Item = item;
+            registry.insert(map_item(&item).unwrap().unwrap());
+        }
+
+        let (resolved, unresolved) = registry.resolve(["Order"]);
+        let names: Vec<_> = resolved.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, ["Customer", "Order", "Tag"]);
+        assert_eq!(unresolved.into_iter().collect::<Vec<_>>(), ["Address"]);
+    }
+}
@@ -0,0 +1,123 @@
+use super::WireType;
+
+/// Map a Rust type to the JSON value serde produces for it
+pub fn map_rust_type(ty: &syn::Type) -> WireType {
+    match ty {
+        syn::Type::Path(type_path) => map_type_path(type_path),
+        syn::Type::Reference(reference) => map_rust_type(&reference.elem),
+        syn::Type::Paren(paren) => map_rust_type(&paren.elem),
+        syn::Type::Group(group) => map_rust_type(&group.elem),
+        syn::Type::Slice(slice) => WireType::Array(Box::new(map_rust_type(&slice.elem))),
+        syn::Type::Array(array) => WireType::Array(Box::new(map_rust_type(&array.elem))),
+        syn::Type::Tuple(tuple) if tuple.elems.is_empty() => WireType::Null,
+        syn::Type::Tuple(tuple) => WireType::Tuple(tuple.elems.iter().map(map_rust_type).collect()),
+        _ => WireType::Unknown,
+    }
+}
+
+fn map_type_path(type_path: &syn::TypePath) -> WireType {
+    let Some(segment) = type_path.path.segments.last() else {
+        return WireType::Unknown;
+    };
+    let generics = generic_type_args(segment);
+
+    match segment.ident.to_string().as_str() {
+        "String" | "str" | "char" | "Uuid" | "DateTime" | "NaiveDate" | "NaiveDateTime"
+        | "NaiveTime" | "Date" | "Time" | "PrimitiveDateTime" | "OffsetDateTime" | "PathBuf"
+        | "Path" | "Decimal" | "Url" => WireType::String,
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" | "f32" | "f64" => WireType::Number,
+        "bool" => WireType::Boolean,
+        "Value" | "Json" => WireType::Unknown,
+        "Option" => match generics.first() {
+            Some(inner) => WireType::Nullable(Box::new(map_rust_type(inner))),
+            None => WireType::Unknown,
+        },
+        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" | "IndexSet" | "LinkedList" => {
+            match generics.first() {
+                Some(inner) => WireType::Array(Box::new(map_rust_type(inner))),
+                None => WireType::Array(Box::new(WireType::Unknown)),
+            }
+        }
+        "HashMap" | "BTreeMap" | "IndexMap" => match generics.get(1) {
+            Some(value) => WireType::Record(Box::new(map_rust_type(value))),
+            None => WireType::Record(Box::new(WireType::Unknown)),
+        },
+        "Box" | "Rc" | "Arc" | "Cow" => match generics.first() {
+            Some(inner) => map_rust_type(inner),
+            None => WireType::Unknown,
+        },
+        name => WireType::Reference(name.to_string()),
+    }
+}
+
+/// Type arguments of a path segment, ignoring lifetimes and const generics
+fn generic_type_args(segment: &syn::PathSegment) -> Vec<&syn::Type> {
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(generics) => generics
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn map(ty: syn::Type) -> WireType {
+        map_rust_type(&ty)
+    }
+
+    #[test]
+    fn primitives_map_to_json_values() {
+        assert_eq!(map(parse_quote!(String)), WireType::String);
+        assert_eq!(map(parse_quote!(&'a str)), WireType::String);
+        assert_eq!(map(parse_quote!(uuid::Uuid)), WireType::String);
+        assert_eq!(map(parse_quote!(u64)), WireType::Number);
+        assert_eq!(map(parse_quote!(f32)), WireType::Number);
+        assert_eq!(map(parse_quote!(bool)), WireType::Boolean);
+        assert_eq!(map(parse_quote!(())), WireType::Null);
+        assert_eq!(map(parse_quote!(serde_json::Value)), WireType::Unknown);
+    }
+
+    #[test]
+    fn containers_map_to_their_items() {
+        assert_eq!(
+            map(parse_quote!(Option<Vec<i32>>)),
+            WireType::Nullable(Box::new(WireType::Array(Box::new(WireType::Number))))
+        );
+        assert_eq!(
+            map(parse_quote!(HashMap<String, bool>)),
+            WireType::Record(Box::new(WireType::Boolean))
+        );
+        assert_eq!(
+            map(parse_quote!([u8; 4])),
+            WireType::Array(Box::new(WireType::Number))
+        );
+        assert_eq!(
+            map(parse_quote!((i32, String))),
+            WireType::Tuple(vec![WireType::Number, WireType::String])
+        );
+        assert_eq!(map(parse_quote!(Arc<Cow<'static, str>>)), WireType::String);
+    }
+
+    #[test]
+    fn other_types_are_references() {
+        assert_eq!(
+            map(parse_quote!(crate::dto::User)),
+            WireType::Reference("User".to_string())
+        );
+        assert_eq!(
+            map(parse_quote!(Box<Tree>)),
+            WireType::Reference("Tree".to_string())
+        );
+        assert_eq!(map(parse_quote!(fn() -> i32)), WireType::Unknown);
+    }
+}
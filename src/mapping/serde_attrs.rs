@@ -0,0 +1,361 @@
+use syn::meta::ParseNestedMeta;
+
+/// Serde `rename_all` rules, applied exactly like serde does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    pub fn parse(rule: &str) -> Option<Self> {
+        match rule {
+            "lowercase" => Some(Self::Lower),
+            "UPPERCASE" => Some(Self::Upper),
+            "PascalCase" => Some(Self::Pascal),
+            "camelCase" => Some(Self::Camel),
+            "snake_case" => Some(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnake),
+            "kebab-case" => Some(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebab),
+            _ => None,
+        }
+    }
+
+    /// Apply the rule to a variant name, which serde assumes is PascalCase
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_string(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            Self::Snake => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            Self::ScreamingSnake => Self::Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            Self::Kebab => Self::Snake.apply_to_variant(variant).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+
+    /// Apply the rule to a field name, which serde assumes is snake_case
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_string(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            Self::Camel => {
+                let pascal = Self::Pascal.apply_to_field(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
+
+/// Serde attributes found on a struct or enum
+#[derive(Debug, Default)]
+pub struct ContainerAttrs {
+    pub rename_all: Option<RenameRule>,
+    pub rename_all_fields: Option<RenameRule>,
+    pub tag: Option<String>,
+    pub content: Option<String>,
+    pub untagged: bool,
+    pub transparent: bool,
+    pub default: bool,
+    pub into: Option<String>,
+    pub unsupported: Vec<String>,
+}
+
+/// Serde attributes found on a field
+#[derive(Debug, Default)]
+pub struct FieldAttrs {
+    pub rename: Option<String>,
+    pub skip: bool,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
+    pub skip_serializing_if: bool,
+    pub default: bool,
+    pub flatten: bool,
+    pub unsupported: Vec<String>,
+}
+
+/// Serde attributes found on an enum variant
+#[derive(Debug, Default)]
+pub struct VariantAttrs {
+    pub rename: Option<String>,
+    pub rename_all: Option<RenameRule>,
+    pub skip: bool,
+    pub untagged: bool,
+    pub unsupported: Vec<String>,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for_each_serde_meta(attrs, |meta, key| {
+            match key.as_str() {
+                "rename_all" => result.rename_all = parse_rename_rule(&meta)?,
+                "rename_all_fields" => result.rename_all_fields = parse_rename_rule(&meta)?,
+                "tag" => result.tag = Some(parse_name(&meta)?),
+                "content" => result.content = Some(parse_name(&meta)?),
+                "untagged" => result.untagged = true,
+                "transparent" => result.transparent = true,
+                "default" => {
+                    skip_meta_value(&meta)?;
+                    result.default = true;
+                }
+                "into" => result.into = Some(parse_type_name(&meta)?),
+                // These do not change the JSON shape
+                "rename"
+                | "deny_unknown_fields"
+                | "bound"
+                | "crate"
+                | "expecting"
+                | "variant_identifier"
+                | "field_identifier" => skip_meta_value(&meta)?,
+                _ => {
+                    skip_meta_value(&meta)?;
+                    result.unsupported.push(key);
+                }
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for_each_serde_meta(attrs, |meta, key| {
+            match key.as_str() {
+                "rename" => result.rename = Some(parse_name(&meta)?),
+                "skip" => result.skip = true,
+                "skip_serializing" => result.skip_serializing = true,
+                "skip_deserializing" => result.skip_deserializing = true,
+                "skip_serializing_if" => {
+                    skip_meta_value(&meta)?;
+                    result.skip_serializing_if = true;
+                }
+                "default" => {
+                    skip_meta_value(&meta)?;
+                    result.default = true;
+                }
+                "flatten" => result.flatten = true,
+                // These do not change the JSON shape
+                "alias" | "bound" | "borrow" => skip_meta_value(&meta)?,
+                _ => {
+                    skip_meta_value(&meta)?;
+                    result.unsupported.push(key);
+                }
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+
+    /// Whether the field never appears in the JSON at all
+    pub fn is_skipped(&self) -> bool {
+        self.skip || (self.skip_serializing && self.skip_deserializing)
+    }
+}
+
+impl VariantAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for_each_serde_meta(attrs, |meta, key| {
+            match key.as_str() {
+                "rename" => result.rename = Some(parse_name(&meta)?),
+                "rename_all" => result.rename_all = parse_rename_rule(&meta)?,
+                "skip" => result.skip = true,
+                "untagged" => result.untagged = true,
+                // These do not change the JSON shape
+                "alias" | "bound" | "other" | "borrow" => skip_meta_value(&meta)?,
+                _ => {
+                    skip_meta_value(&meta)?;
+                    result.unsupported.push(key);
+                }
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+}
+
+/// Call `f` for every key inside `#[serde(...)]` attributes
+fn for_each_serde_meta(
+    attrs: &[syn::Attribute],
+    mut f: impl FnMut(ParseNestedMeta, String) -> syn::Result<()>,
+) -> syn::Result<()> {
+    for attr in attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            let key = meta
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+            f(meta, key)
+        })?;
+    }
+    Ok(())
+}
+
+/// Parse `key = "value"` or `key(serialize = "value", deserialize = "...")`,
+/// preferring the serialize side since that is what clients receive
+fn parse_name(meta: &ParseNestedMeta) -> syn::Result<String> {
+    if meta.input.peek(syn::Token![=]) {
+        let lit: syn::LitStr = meta.value()?.parse()?;
+        return Ok(lit.value());
+    }
+
+    let mut serialize = None;
+    let mut deserialize = None;
+    meta.parse_nested_meta(|nested| {
+        let lit: syn::LitStr = nested.value()?.parse()?;
+        if nested.path.is_ident("serialize") {
+            serialize = Some(lit.value());
+        } else if nested.path.is_ident("deserialize") {
+            deserialize = Some(lit.value());
+        }
+        Ok(())
+    })?;
+
+    serialize
+        .or(deserialize)
+        .ok_or_else(|| meta.error("expected serialize or deserialize name"))
+}
+
+fn parse_rename_rule(meta: &ParseNestedMeta) -> syn::Result<Option<RenameRule>> {
+    let rule = parse_name(meta)?;
+    RenameRule::parse(&rule)
+        .map(Some)
+        .ok_or_else(|| meta.error(format!("unknown rename rule `{rule}`")))
+}
+
+/// Parse `into = "path::to::Type"` and keep the last path segment
+fn parse_type_name(meta: &ParseNestedMeta) -> syn::Result<String> {
+    let lit: syn::LitStr = meta.value()?.parse()?;
+    let path: syn::Path = lit.parse()?;
+    path.segments
+        .last()
+        .map(|segment| segment.ident.to_string())
+        .ok_or_else(|| meta.error("expected a type path"))
+}
+
+/// Consume an optional `= value` or `(...)` following a key
+fn skip_meta_value(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| skip_meta_value(&nested))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn rename_rules_match_serde() {
+        let rules = [
+            ("lowercase", "userid", "user_id"),
+            ("UPPERCASE", "USERID", "USER_ID"),
+            ("PascalCase", "UserId", "UserId"),
+            ("camelCase", "userId", "userId"),
+            ("snake_case", "user_id", "user_id"),
+            ("SCREAMING_SNAKE_CASE", "USER_ID", "USER_ID"),
+            ("kebab-case", "user-id", "user-id"),
+            ("SCREAMING-KEBAB-CASE", "USER-ID", "USER-ID"),
+        ];
+        for (name, variant, field) in rules {
+            let rule = RenameRule::parse(name).unwrap();
+            assert_eq!(rule.apply_to_variant("UserId"), variant, "{name}");
+            assert_eq!(rule.apply_to_field("user_id"), field, "{name}");
+        }
+        assert_eq!(RenameRule::parse("Title Case"), None);
+    }
+
+    #[test]
+    fn container_attrs_collect_unsupported_keys() {
+        let attrs: Vec<syn::Attribute> = vec![
+            parse_quote!(#[serde(tag = "type", rename_all = "camelCase")]),
+            parse_quote!(#[serde(deny_unknown_fields, remote = "Other")]),
+        ];
+        let parsed = ContainerAttrs::parse(&attrs).unwrap();
+        assert_eq!(parsed.tag.as_deref(), Some("type"));
+        assert_eq!(parsed.rename_all, Some(RenameRule::Camel));
+        assert_eq!(parsed.unsupported, ["remote"]);
+    }
+
+    #[test]
+    fn unknown_rename_rules_are_errors() {
+        let attrs: Vec<syn::Attribute> = vec![parse_quote!(#[serde(rename_all = "Sponge")])];
+        assert!(ContainerAttrs::parse(&attrs).is_err());
+    }
+
+    #[test]
+    fn field_attrs_prefer_the_serialize_name() {
+        let attrs: Vec<syn::Attribute> = vec![
+            parse_quote!(#[serde(rename(serialize = "out", deserialize = "in"))]),
+            parse_quote!(#[serde(skip_serializing, skip_deserializing, alias = "x")]),
+        ];
+        let parsed = FieldAttrs::parse(&attrs).unwrap();
+        assert_eq!(parsed.rename.as_deref(), Some("out"));
+        assert!(parsed.is_skipped());
+        assert!(parsed.unsupported.is_empty());
+    }
+
+    #[test]
+    fn attributes_of_other_derives_are_ignored() {
+        let attrs: Vec<syn::Attribute> = vec![parse_quote!(#[validate(length(min = 1))])];
+        let parsed = FieldAttrs::parse(&attrs).unwrap();
+        assert_eq!(parsed.rename, None);
+        assert!(parsed.unsupported.is_empty());
+    }
+}
@@ -0,0 +1,285 @@
+use syn::meta::ParseNestedMeta;
+
+/// A single rule from a `validator` derive attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ValidationRule {
+    Length {
+        min: Option<String>,
+        max: Option<String>,
+        equal: Option<String>,
+    },
+    Range {
+        min: Option<String>,
+        max: Option<String>,
+        exclusive_min: Option<String>,
+        exclusive_max: Option<String>,
+    },
+    Email,
+    Url,
+    Contains(String),
+    DoesNotContain(String),
+    /// Must equal the field with this Rust name
+    MustMatch(String),
+    Required,
+    NonControlCharacter,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Validation {
+    pub rule: ValidationRule,
+    pub message: Option<String>,
+}
+
+/// Parse every `#[validate(...)]` attribute on a field, returning the rules we
+/// understand and the names of the ones we cannot model
+pub fn parse_validations(attrs: &[syn::Attribute]) -> syn::Result<(Vec<Validation>, Vec<String>)> {
+    let mut validations = Vec::new();
+    let mut unsupported = Vec::new();
+
+    for attr in attrs {
+        if !attr.path().is_ident("validate") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            let key = meta
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+
+            let mut args = RuleArgs::default();
+            match key.as_str() {
+                "email" => {
+                    args.parse(&meta)?;
+                    validations.push(args.into_validation(ValidationRule::Email));
+                }
+                "url" => {
+                    args.parse(&meta)?;
+                    validations.push(args.into_validation(ValidationRule::Url));
+                }
+                "required" => {
+                    args.parse(&meta)?;
+                    validations.push(args.into_validation(ValidationRule::Required));
+                }
+                "non_control_character" => {
+                    args.parse(&meta)?;
+                    validations.push(args.into_validation(ValidationRule::NonControlCharacter));
+                }
+                "length" => {
+                    args.parse(&meta)?;
+                    let rule = ValidationRule::Length {
+                        min: args.take_number("min", &mut unsupported),
+                        max: args.take_number("max", &mut unsupported),
+                        equal: args.take_number("equal", &mut unsupported),
+                    };
+                    validations.push(args.into_validation(rule));
+                }
+                "range" => {
+                    args.parse(&meta)?;
+                    let rule = ValidationRule::Range {
+                        min: args.take_number("min", &mut unsupported),
+                        max: args.take_number("max", &mut unsupported),
+                        exclusive_min: args.take_number("exclusive_min", &mut unsupported),
+                        exclusive_max: args.take_number("exclusive_max", &mut unsupported),
+                    };
+                    validations.push(args.into_validation(rule));
+                }
+                "contains" | "does_not_contain" | "must_match" => {
+                    args.parse(&meta)?;
+                    let value_key = if key == "must_match" {
+                        "other"
+                    } else {
+                        "pattern"
+                    };
+                    match args.take_string(value_key) {
+                        Some(value) => {
+                            let rule = match key.as_str() {
+                                "contains" => ValidationRule::Contains(value),
+                                "does_not_contain" => ValidationRule::DoesNotContain(value),
+                                _ => ValidationRule::MustMatch(value),
+                            };
+                            validations.push(args.into_validation(rule));
+                        }
+                        None => unsupported.push(key),
+                    }
+                }
+                // Nested DTOs are validated through their own schema
+                "nested" | "required_nested" => args.parse(&meta)?,
+                _ => {
+                    args.parse(&meta)?;
+                    unsupported.push(key);
+                }
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok((validations, unsupported))
+}
+
+/// Arguments of a rule, either `rule = value` or `rule(key = value, ...)`
+#[derive(Default)]
+struct RuleArgs {
+    value: Option<syn::Expr>,
+    named: Vec<(String, syn::Expr)>,
+}
+
+impl RuleArgs {
+    fn parse(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if meta.input.peek(syn::Token![=]) {
+            self.value = Some(meta.value()?.parse()?);
+        } else if meta.input.peek(syn::token::Paren) {
+            meta.parse_nested_meta(|nested| {
+                let key = nested
+                    .path
+                    .get_ident()
+                    .map(|ident| ident.to_string())
+                    .unwrap_or_default();
+                if nested.input.peek(syn::Token![=]) {
+                    let value: syn::Expr = nested.value()?.parse()?;
+                    self.named.push((key, value));
+                } else if nested.input.peek(syn::token::Paren) {
+                    // e.g. `regex(path = *RE)` nested one level deeper; not modelled
+                    nested.parse_nested_meta(|_| Ok(()))?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
+    }
+
+    fn take(&mut self, key: &str) -> Option<syn::Expr> {
+        let index = self.named.iter().position(|(name, _)| name == key)?;
+        Some(self.named.remove(index).1)
+    }
+
+    /// Numeric literal argument; constants and expressions cannot be evaluated here
+    fn take_number(&mut self, key: &str, unsupported: &mut Vec<String>) -> Option<String> {
+        let expr = self.take(key)?;
+        let number = number_literal(&expr);
+        if number.is_none() {
+            unsupported.push(format!("{key} = {}", quote::quote!(#expr)));
+        }
+        number
+    }
+
+    /// String argument, accepting both `rule = "x"` and `rule(key = "x")`
+    fn take_string(&mut self, key: &str) -> Option<String> {
+        let expr = self.take(key).or_else(|| self.value.take())?;
+        string_literal(&expr)
+    }
+
+    fn into_validation(mut self, rule: ValidationRule) -> Validation {
+        let message = self.take("message").and_then(|expr| string_literal(&expr));
+        Validation { rule, message }
+    }
+}
+
+fn number_literal(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
+            syn::Lit::Int(int) => Some(int.base10_digits().to_string()),
+            syn::Lit::Float(float) => Some(float.base10_digits().to_string()),
+            _ => None,
+        },
+        syn::Expr::Unary(unary) if matches!(unary.op, syn::UnOp::Neg(_)) => {
+            number_literal(&unary.expr).map(|number| format!("-{number}"))
+        }
+        _ => None,
+    }
+}
+
+fn string_literal(expr: &syn::Expr) -> Option<String> {
+    if let syn::Expr::Lit(expr_lit) = expr
+        && let syn::Lit::Str(lit_str) = &expr_lit.lit
+    {
+        return Some(lit_str.value());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn parse(attr: syn::Attribute) -> (Vec<Validation>, Vec<String>) {
+        parse_validations(&[attr]).unwrap()
+    }
+
+    fn rules(attr: syn::Attribute) -> Vec<ValidationRule> {
+        parse(attr).0.into_iter().map(|v| v.rule).collect()
+    }
+
+    #[test]
+    fn length_and_range_keep_numeric_literals() {
+        assert_eq!(
+            rules(parse_quote!(#[validate(length(min = 1, max = 64))])),
+            [ValidationRule::Length {
+                min: Some("1".to_string()),
+                max: Some("64".to_string()),
+                equal: None,
+            }]
+        );
+        assert_eq!(
+            rules(parse_quote!(#[validate(range(min = -1.5, exclusive_max = 10))])),
+            [ValidationRule::Range {
+                min: Some("-1.5".to_string()),
+                max: None,
+                exclusive_min: None,
+                exclusive_max: Some("10".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn string_rules_accept_both_forms() {
+        assert_eq!(
+            rules(parse_quote!(#[validate(contains = "@", does_not_contain(pattern = "admin"))])),
+            [
+                ValidationRule::Contains("@".to_string()),
+                ValidationRule::DoesNotContain("admin".to_string()),
+            ]
+        );
+        assert_eq!(
+            rules(parse_quote!(#[validate(must_match(other = "password"))])),
+            [ValidationRule::MustMatch("password".to_string())]
+        );
+    }
+
+    #[test]
+    fn messages_are_kept() {
+        let (validations, _) = parse(parse_quote!(
+            #[validate(email(message = "Invalid email"), url, required, non_control_character)]
+        ));
+        assert_eq!(validations[0].rule, ValidationRule::Email);
+        assert_eq!(validations[0].message.as_deref(), Some("Invalid email"));
+        assert_eq!(
+            validations[1..]
+                .iter()
+                .map(|v| v.rule.clone())
+                .collect::<Vec<_>>(),
+            [
+                ValidationRule::Url,
+                ValidationRule::Required,
+                ValidationRule::NonControlCharacter
+            ]
+        );
+    }
+
+    #[test]
+    fn unmodelled_rules_are_reported() {
+        let (validations, unsupported) = parse(parse_quote!(
+            #[validate(length(min = MIN_LEN), custom(function = "check"), regex(path = *RE), nested)]
+        ));
+        assert_eq!(
+            validations.into_iter().map(|v| v.rule).collect::<Vec<_>>(),
+            [ValidationRule::Length {
+                min: None,
+                max: None,
+                equal: None
+            }]
+        );
+        assert_eq!(unsupported, ["min = MIN_LEN", "custom", "regex"]);
+    }
+}
@@ -0,0 +1,117 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// What happened to a generated file on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteStatus {
+    Created,
+    Updated,
+    Unchanged,
+    /// Compared against disk in check mode and found up to date, nothing was written
+    Checked,
+}
+
+/// A file written by `generate_links`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
+    pub path: PathBuf,
+    pub status: WriteStatus,
+}
+
+/// Result of `generate_links`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerationResult {
+    /// Generated Rust links enum
+    pub rust_code: String,
+    /// Every output file and whether it was created, updated, left untouched or checked
+    pub files: Vec<GeneratedFile>,
+}
+
+impl GenerationResult {
+    /// Files that were created or updated by this run
+    pub fn changed_files(&self) -> impl Iterator<Item = &GeneratedFile> {
+        self.files
+            .iter()
+            .filter(|file| matches!(file.status, WriteStatus::Created | WriteStatus::Updated))
+    }
+}
+
+/// Write `content` to `path` unless it already holds exactly that content,
+/// so unchanged outputs keep their mtime and don't trigger rebuilds or reloads
+pub fn write_if_changed(path: &Path, content: &str) -> io::Result<WriteStatus> {
+    let status = match fs::read(path) {
+        Ok(existing) if existing == content.as_bytes() => return Ok(WriteStatus::Unchanged),
+        Ok(_) => WriteStatus::Updated,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => WriteStatus::Created,
+        Err(e) => return Err(e),
+    };
+
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)?;
+    }
+
+    write_atomic(path, content)?;
+    Ok(status)
+}
+
+/// Write through a temporary file in the same directory and rename it into place,
+/// so readers never observe a partially written file
+fn write_atomic(path: &Path, content: &str) -> io::Result<()> {
+    let file_name = path.file_name().ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "output path has no file name")
+    })?;
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    fs::write(&temp_path, content)?;
+    fs::rename(&temp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&temp_path);
+    })
+}
+
+/// Compare planned outputs against the files on disk without writing anything,
+/// failing with a unified diff for every file that is missing or out of date
+pub fn check_outputs(outputs: &[(PathBuf, String)]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut stale = Vec::new();
+
+    for (path, content) in outputs {
+        let existing = match fs::read_to_string(path) {
+            Ok(existing) => Some(existing),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e).into()),
+        };
+        if existing.as_deref() == Some(content.as_str()) {
+            continue;
+        }
+
+        let old_header = match &existing {
+            Some(_) => path.display().to_string(),
+            None => "/dev/null".to_string(),
+        };
+        let new_header = format!("{} (generated)", path.display());
+        let diff =
+            similar::TextDiff::from_lines(existing.as_deref().unwrap_or(""), content.as_str())
+                .unified_diff()
+                .context_radius(3)
+                .header(&old_header, &new_header)
+                .to_string();
+        stale.push(diff);
+    }
+
+    if stale.is_empty() {
+        return Ok(());
+    }
+
+    Err(format!(
+        "{} generated file(s) are stale, rerun the build without check mode:\n\n{}",
+        stale.len(),
+        stale.join("\n")
+    )
+    .into())
+}
@@ -0,0 +1,302 @@
+use super::RouteInfo;
+use crate::config::Config;
+use crate::utils::hash::stable_fingerprint;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CACHE_FILE: &str = "route-info-builder-cache.json";
+
+/// Routes extracted from controller files, keyed by file path and content hash
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RouteCache {
+    /// Crate version and naming configuration the entries were produced with
+    fingerprint: String,
+    files: BTreeMap<PathBuf, CachedFile>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedFile {
+    content_hash: u64,
+    routes: Vec<RouteInfo>,
+}
+
+/// Parse the given controller files, reusing cached routes for unchanged files
+/// and parsing the changed ones in parallel. Results keep the order of `files`.
+pub fn parse_controller_files(
+    files: &[PathBuf],
+    config: &Config,
+) -> Result<Vec<Vec<RouteInfo>>, Box<dyn std::error::Error>> {
+    parse_with_cache(files, config, cache_path(config).as_deref())
+}
+
+fn parse_with_cache(
+    files: &[PathBuf],
+    config: &Config,
+    cache_path: Option<&Path>,
+) -> Result<Vec<Vec<RouteInfo>>, Box<dyn std::error::Error>> {
+    let fingerprint = format!(
+        "{}-{:016x}",
+        env!("CARGO_PKG_VERSION"),
+        stable_fingerprint(serde_json::to_string(&config.naming)?.as_str())
+    );
+
+    let mut cache = cache_path
+        .and_then(load_cache)
+        .filter(|cache| cache.fingerprint == fingerprint)
+        .unwrap_or_default();
+
+    let mut contents = Vec::with_capacity(files.len());
+    for file in files {
+        let content = fs::read_to_string(file)
+            .map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+        contents.push(content);
+    }
+    let hashes: Vec<u64> = contents
+        .iter()
+        .map(|content| stable_fingerprint(content.as_str()))
+        .collect();
+
+    // Only files whose content changed since the last build need parsing
+    let stale: Vec<usize> = (0..files.len())
+        .filter(|&i| {
+            cache
+                .files
+                .get(&files[i])
+                .is_none_or(|cached| cached.content_hash != hashes[i])
+        })
+        .collect();
+
+    let parsed = parse_in_parallel(&stale, files, &contents, config)?;
+    for (i, routes) in stale.into_iter().zip(parsed) {
+        cache.files.insert(
+            files[i].clone(),
+            CachedFile {
+                content_hash: hashes[i],
+                routes,
+            },
+        );
+    }
+
+    let results = files
+        .iter()
+        .map(|file| {
+            cache
+                .files
+                .get(file)
+                .map(|cached| cached.routes.clone())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    // Drop entries for deleted controllers before persisting
+    cache.files.retain(|path, _| files.contains(path));
+    cache.fingerprint = fingerprint;
+    if let Some(cache_path) = cache_path {
+        save_cache(cache_path, &cache);
+    }
+
+    Ok(results)
+}
+
+fn parse_in_parallel(
+    indices: &[usize],
+    files: &[PathBuf],
+    contents: &[String],
+    config: &Config,
+) -> Result<Vec<Vec<RouteInfo>>, Box<dyn std::error::Error>> {
+    if indices.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(indices.len());
+    let chunk_size = indices.len().div_ceil(threads);
+
+    // Errors are stringified because boxed errors cannot cross threads
+    let chunks: Vec<Result<Vec<Vec<RouteInfo>>, String>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = indices
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|&i| {
+                            super::parse_routes_from_source(&files[i], &contents[i], config)
+                                .map(Option::unwrap_or_default)
+                                .map_err(|e| e.to_string())
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Err("Controller parsing thread panicked".to_string()))
+            })
+            .collect()
+    });
+
+    let mut results = Vec::with_capacity(indices.len());
+    for chunk in chunks {
+        results.extend(chunk?);
+    }
+    Ok(results)
+}
+
+/// Cache file inside `OUT_DIR`, only available when running from a build script
+fn cache_path(config: &Config) -> Option<PathBuf> {
+    if !config.cache.unwrap_or(true) {
+        return None;
+    }
+    std::env::var_os("OUT_DIR").map(|out_dir| PathBuf::from(out_dir).join(CACHE_FILE))
+}
+
+fn load_cache(path: &Path) -> Option<RouteCache> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn save_cache(path: &Path, cache: &RouteCache) {
+    let result = serde_json::to_string(cache)
+        .map_err(|e| e.to_string())
+        .and_then(|json| fs::write(path, json).map_err(|e| e.to_string()));
+    if let Err(e) = result {
+        println!(
+            "cargo:warning=Failed to write route cache {}: {}",
+            path.display(),
+            e
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NamingConfig;
+
+    const CONTROLLER: &str = r#"
+pub async fn list() -> Result<Response> {
+    format::empty()
+}
+
+pub fn routes() -> Routes {
+    Routes::new().prefix("api/users").add("/", get(list))
+}
+"#;
+
+    /// Controller file and cache file in a fresh directory, removed on drop
+    struct Fixture {
+        dir: PathBuf,
+        controller: PathBuf,
+        cache: PathBuf,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "route-info-builder-cache-{}-{}",
+                name,
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            let controller = dir.join("users.rs");
+            fs::write(&controller, CONTROLLER).unwrap();
+            let cache = dir.join(CACHE_FILE);
+            Fixture {
+                dir,
+                controller,
+                cache,
+            }
+        }
+
+        fn parse(&self, config: &Config) -> Vec<String> {
+            parse_with_cache(
+                std::slice::from_ref(&self.controller),
+                config,
+                Some(&self.cache),
+            )
+            .unwrap()
+            .concat()
+            .into_iter()
+            .map(|route| route.name)
+            .collect()
+        }
+
+        /// Rename the cached routes so a cache hit is visible in the result
+        fn mark_cached_routes(&self) {
+            let mut cache = load_cache(&self.cache).unwrap();
+            for file in cache.files.values_mut() {
+                for route in &mut file.routes {
+                    route.name = "from_cache".to_string();
+                }
+            }
+            save_cache(&self.cache, &cache);
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[test]
+    fn unchanged_files_are_read_from_the_cache() {
+        let fixture = Fixture::new("hit");
+        let config = Config::default();
+        assert_eq!(fixture.parse(&config), ["get_api_users"]);
+
+        fixture.mark_cached_routes();
+        assert_eq!(fixture.parse(&config), ["from_cache"]);
+    }
+
+    #[test]
+    fn edited_files_are_parsed_again() {
+        let fixture = Fixture::new("edit");
+        let config = Config::default();
+        fixture.parse(&config);
+        fixture.mark_cached_routes();
+
+        fs::write(
+            &fixture.controller,
+            CONTROLLER.replace("api/users", "api/people"),
+        )
+        .unwrap();
+        assert_eq!(fixture.parse(&config), ["get_api_people"]);
+    }
+
+    #[test]
+    fn naming_changes_invalidate_the_cache() {
+        let fixture = Fixture::new("naming");
+        fixture.parse(&Config::default());
+        fixture.mark_cached_routes();
+
+        let config = Config {
+            naming: NamingConfig {
+                path_prefix_to_remove: Some("/api".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(fixture.parse(&config), ["get_users"]);
+    }
+
+    #[test]
+    fn corrupt_cache_files_are_ignored_and_replaced() {
+        let fixture = Fixture::new("corrupt");
+        fs::write(&fixture.cache, "{ not json").unwrap();
+
+        let config = Config::default();
+        assert_eq!(fixture.parse(&config), ["get_api_users"]);
+        assert!(load_cache(&fixture.cache).is_some());
+    }
+}
@@ -1,8 +1,10 @@
 use super::HandlerInfo;
+use quote::ToTokens;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use syn::Pat;
 
-#[derive(Default, Debug, PartialEq, Eq, Hash, Clone)]
+#[derive(Default, Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct ReturnTypeVisitor {
     pub found_type: Option<String>,
     pub is_importable: bool,
@@ -303,6 +305,7 @@ pub fn extract_handler_info(
             let handler_name = func.sig.ident.to_string();
             let mut body_param = None;
             let mut query_params = None;
+            let mut path_params = Vec::new();
             let mut requires_auth = false;
             let mut return_type = ReturnTypeVisitor::default();
 
@@ -340,6 +343,21 @@ pub fn extract_handler_info(
                                 query_params = Some(param_segment.ident.to_string());
                             }
                         }
+                        // Handle Path<T> and Path<(A, B)>
+                        else if type_ident == "Path"
+                            && let syn::PathArguments::AngleBracketed(generics) = &segment.arguments
+                            && let Some(syn::GenericArgument::Type(param_type)) =
+                                generics.args.first()
+                        {
+                            path_params = match param_type {
+                                syn::Type::Tuple(tuple) => tuple
+                                    .elems
+                                    .iter()
+                                    .map(|elem| elem.to_token_stream().to_string())
+                                    .collect(),
+                                other => vec![other.to_token_stream().to_string()],
+                            };
+                        }
                     }
 
                     // Check for authentication (auth: JWT)
@@ -362,6 +380,7 @@ pub fn extract_handler_info(
                 HandlerInfo {
                     body_param,
                     query_params,
+                    path_params,
                     requires_auth,
                     return_type,
                 },
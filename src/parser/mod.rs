@@ -1,24 +1,31 @@
+mod cache;
 mod handlers;
 
-use crate::config::Config;
+use crate::config::{Config, RouteOrder};
+use crate::mapping::TypeRegistry;
 use crate::parser::handlers::ReturnTypeVisitor;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RouteInfo {
     pub name: String,
     pub path: String,
     pub method: String,
     pub handler: String,
+    /// Controller module the route was declared in (file stem, e.g. `users`)
+    pub controller: String,
     pub handler_info: HandlerInfo,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct HandlerInfo {
     pub body_param: Option<String>,
     pub query_params: Option<String>, // NEW: Query parameter type
+    /// Types of the `Path<T>` extractor, one entry per element of a tuple
+    pub path_params: Vec<String>,
     pub requires_auth: bool,
     pub return_type: handlers::ReturnTypeVisitor,
 }
@@ -27,7 +34,7 @@ pub fn scan_controllers_folder(
     config: &Config,
 ) -> Result<Vec<RouteInfo>, Box<dyn std::error::Error>> {
     let controllers_dir = &config.controllers_path;
-    let mut routes = Vec::new();
+    let mut files = Vec::new();
 
     let entries = fs::read_dir(controllers_dir).map_err(|e| {
         format!(
@@ -45,12 +52,25 @@ pub fn scan_controllers_folder(
             && let Some(filename) = path.file_name().and_then(|s| s.to_str())
             && filename != "mod.rs"
             && filename.ends_with(".rs")
-            && let Some(file_routes) = parse_routes_from_file(&path, config)?
         {
-            routes.extend(file_routes);
+            files.push(path);
         }
     }
 
+    // `read_dir` order differs between platforms and filesystems
+    files.sort();
+
+    // Rebuild when a controller is added, removed or edited
+    emit_rerun_if_changed(controllers_dir);
+    for file in &files {
+        emit_rerun_if_changed(file);
+    }
+
+    let mut routes: Vec<RouteInfo> = cache::parse_controller_files(&files, config)?
+        .into_iter()
+        .flatten()
+        .collect();
+
     // Deduplicate routes by (method, path) combination
     let mut seen = HashSet::new();
     routes.retain(|route| {
@@ -67,15 +87,107 @@ pub fn scan_controllers_folder(
         }
     });
 
+    // Every generator derives its identifiers from these names, so they must be unique
+    let mut names: Vec<(String, &RouteInfo)> = routes
+        .iter()
+        .map(|route| (route.name.clone(), route))
+        .collect();
+    crate::config::naming::resolve_name_collisions(&mut names, &config.naming)?;
+    let names: Vec<String> = names.into_iter().map(|(name, _)| name).collect();
+    for (route, name) in routes.iter_mut().zip(names) {
+        route.name = name;
+    }
+
+    sort_routes(&mut routes, config.route_order.unwrap_or_default());
+
     Ok(routes)
 }
 
-fn parse_routes_from_file(
+/// Order routes by the configured key; sorts are stable so ties keep declaration order
+fn sort_routes(routes: &mut [RouteInfo], order: RouteOrder) {
+    match order {
+        // Controller files are read in sorted order, routes in source order
+        RouteOrder::Declaration => {}
+        RouteOrder::Path => routes.sort_by(|a, b| (&a.path, &a.method).cmp(&(&b.path, &b.method))),
+        RouteOrder::Name => routes.sort_by(|a, b| (&a.name, &a.path).cmp(&(&b.name, &b.path))),
+        RouteOrder::Controller => routes.sort_by(|a, b| a.controller.cmp(&b.controller)),
+    }
+}
+
+/// Collect serde DTO definitions from the controllers folder and the configured DTO paths
+pub fn scan_type_definitions(config: &Config) -> Result<TypeRegistry, Box<dyn std::error::Error>> {
+    let mut sources = vec![config.controllers_path.clone()];
+    if let Some(dto_paths) = &config.typescript.dto_paths {
+        sources.extend(dto_paths.iter().cloned());
+    }
+
+    let mut files = Vec::new();
+    for source in &sources {
+        emit_rerun_if_changed(source);
+        collect_rust_files(source, &mut files)?;
+    }
+    files.sort();
+    for file in &files {
+        emit_rerun_if_changed(file);
+    }
+
+    let mut registry = TypeRegistry::default();
+    for file in &files {
+        let content = fs::read_to_string(file)?;
+        let syntax = syn::parse_file(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", file.display(), e))?;
+        collect_type_definitions(&syntax.items, &mut registry)
+            .map_err(|e| format!("Failed to map DTOs in {}: {}", file.display(), e))?;
+    }
+
+    Ok(registry)
+}
+
+/// Tell Cargo to rerun the build script when `path` changes; only printed from build scripts
+pub(crate) fn emit_rerun_if_changed(path: &Path) {
+    if std::env::var_os("OUT_DIR").is_some() {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+}
+
+fn collect_rust_files(
+    path: &Path,
+    files: &mut Vec<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if path.is_file() {
+        if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path.to_path_buf());
+        }
+        return Ok(());
+    }
+
+    let entries = fs::read_dir(path)
+        .map_err(|e| format!("Failed to read DTO directory {}: {}", path.display(), e))?;
+    for entry in entries {
+        collect_rust_files(&entry?.path(), files)?;
+    }
+    Ok(())
+}
+
+fn collect_type_definitions(items: &[syn::Item], registry: &mut TypeRegistry) -> syn::Result<()> {
+    for item in items {
+        if let syn::Item::Mod(module) = item
+            && let Some((_, module_items)) = &module.content
+        {
+            collect_type_definitions(module_items, registry)?;
+        } else if let Some(definition) = crate::mapping::map_item(item)? {
+            registry.insert(definition);
+        }
+    }
+    Ok(())
+}
+
+fn parse_routes_from_source(
     file_path: &Path,
+    content: &str,
     config: &Config,
 ) -> Result<Option<Vec<RouteInfo>>, Box<dyn std::error::Error>> {
-    let content = fs::read_to_string(file_path)?;
-    let syntax = syn::parse_file(&content)
+    let syntax = syn::parse_file(content)
         .map_err(|e| format!("Failed to parse {}: {}", file_path.display(), e))?;
 
     let mut routes = Vec::new();
@@ -83,7 +195,7 @@ fn parse_routes_from_file(
     for item in &syntax.items {
         if let syn::Item::Fn(func) = item
             && func.sig.ident == "routes"
-            && let Some(routes_vec) = extract_routes_from_axum_function(func, config)?
+            && let Some(routes_vec) = extract_routes_from_axum_function(func)?
         {
             routes = routes_vec;
             break;
@@ -93,8 +205,15 @@ fn parse_routes_from_file(
     // Now extract body parameters and auth requirements from handler functions
     let handler_info_map = handlers::extract_handler_info(&syntax)?;
 
+    let controller = file_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
     // Update routes with handler information
     for route in &mut routes {
+        route.controller = controller.clone();
+        route.name = crate::config::naming::route_name(route, &config.naming)?;
         if let Some(info) = handler_info_map.get(&route.handler) {
             route.handler_info = info.clone(); // Set the complete HandlerInfo
         }
@@ -109,7 +228,6 @@ fn parse_routes_from_file(
 
 fn extract_routes_from_axum_function(
     func: &syn::ItemFn,
-    config: &Config,
 ) -> Result<Option<Vec<RouteInfo>>, Box<dyn std::error::Error>> {
     let block = &func.block;
     let mut routes = Vec::new();
@@ -118,7 +236,7 @@ fn extract_routes_from_axum_function(
     for stmt in &block.stmts {
         match stmt {
             syn::Stmt::Expr(expr, _) => {
-                extract_routes_from_expr(expr, &mut routes, &mut current_prefix, config)?;
+                extract_routes_from_expr(expr, &mut routes, &mut current_prefix)?;
             }
             _ => {} // Skip other statement types
         }
@@ -135,14 +253,13 @@ fn extract_routes_from_expr(
     expr: &syn::Expr,
     routes: &mut Vec<RouteInfo>,
     prefix: &mut String,
-    config: &Config,
 ) -> Result<(), Box<dyn std::error::Error>> {
     match expr {
         syn::Expr::MethodCall(method_call) => {
             let method_name = method_call.method.to_string();
 
             // FIRST process the receiver to establish context (including any prefixes)
-            extract_routes_from_expr(&method_call.receiver, routes, prefix, config)?;
+            extract_routes_from_expr(&method_call.receiver, routes, prefix)?;
 
             // THEN process the current method call
             match method_name.as_str() {
@@ -170,20 +287,17 @@ fn extract_routes_from_expr(
                         // Build full path with current prefix
                         let full_path = crate::utils::path::build_full_path(prefix, &path);
 
-                        let name = crate::config::naming::generate_route_name(
-                            &full_path,
-                            &method,
-                            &config.naming,
-                        );
-
+                        // Named once the controller is known
                         routes.push(RouteInfo {
-                            name,
+                            name: String::new(),
                             path: full_path,
                             method,
                             handler,
+                            controller: String::new(),
                             handler_info: HandlerInfo {
                                 body_param: None,
                                 query_params: None,
+                                path_params: Vec::new(),
                                 requires_auth: false,
                                 return_type: ReturnTypeVisitor::default(),
                             },
@@ -217,7 +331,8 @@ fn extract_string_literal(expr: &syn::Expr) -> Option<String> {
     None
 }
 
-/// Extract both HTTP method and handler function name
+/// Extract both HTTP method and handler function name; closures and other
+/// expressions have an empty handler name
 fn extract_http_method_and_handler(expr: &syn::Expr) -> Option<(String, String)> {
     if let syn::Expr::Call(call_expr) = expr
         && let syn::Expr::Path(func_path) = &*call_expr.func
@@ -226,13 +341,14 @@ fn extract_http_method_and_handler(expr: &syn::Expr) -> Option<(String, String)>
         let method_name = segment.ident.to_string().to_uppercase();
 
         // Extract handler function name from arguments
-        if let Some(handler_expr) = call_expr.args.first()
-            && let syn::Expr::Path(handler_path) = handler_expr
+        let handler_expr = call_expr.args.first()?;
+        if let syn::Expr::Path(handler_path) = handler_expr
             && let Some(handler_segment) = handler_path.path.segments.last()
         {
             let handler_name = handler_segment.ident.to_string();
             return Some((method_name, handler_name));
         }
+        return Some((method_name, String::new()));
     }
     None
 }
@@ -1,15 +1,91 @@
-use convert_case::{Case, Casing};
-
-pub fn convert_to_case(input: &str, case: &str) -> String {
-    match case.to_lowercase().as_str() {
-        "camel" | "camelcase" => input.to_case(Case::Camel),
-        "pascal" | "pascalcase" => input.to_case(Case::Pascal),
-        "snake" | "snake_case" => input.to_case(Case::Snake),
-        "kebab" | "kebab-case" => input.to_case(Case::Kebab),
-        "title" | "title_case" => input.to_case(Case::Title),
-        "lower" | "lowercase" => input.to_lowercase(),
-        "upper" | "uppercase" => input.to_uppercase(),
-        _ => input.to_string(), // Return as-is if unknown case
+use convert_case::{Boundary, Case, Casing};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Case of generated identifiers, e.g. `"PascalCase"` or `"camel"` in configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub enum CaseStyle {
+    Camel,
+    Pascal,
+    Snake,
+    Kebab,
+    Title,
+    Lower,
+    Upper,
+}
+
+impl FromStr for CaseStyle {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "camel" | "camelcase" => Ok(CaseStyle::Camel),
+            "pascal" | "pascalcase" => Ok(CaseStyle::Pascal),
+            "snake" | "snake_case" => Ok(CaseStyle::Snake),
+            "kebab" | "kebab-case" => Ok(CaseStyle::Kebab),
+            "title" | "title_case" => Ok(CaseStyle::Title),
+            "lower" | "lowercase" => Ok(CaseStyle::Lower),
+            "upper" | "uppercase" => Ok(CaseStyle::Upper),
+            _ => Err(format!(
+                "Unknown case '{}', expected \"camelCase\", \"PascalCase\", \"snake_case\", \"kebab-case\", \"title_case\", \"lowercase\" or \"UPPERCASE\"",
+                value
+            )),
+        }
+    }
+}
+
+impl TryFrom<String> for CaseStyle {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for CaseStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CaseStyle::Camel => "camelCase",
+            CaseStyle::Pascal => "PascalCase",
+            CaseStyle::Snake => "snake_case",
+            CaseStyle::Kebab => "kebab-case",
+            CaseStyle::Title => "title_case",
+            CaseStyle::Lower => "lowercase",
+            CaseStyle::Upper => "UPPERCASE",
+        })
+    }
+}
+
+impl Serialize for CaseStyle {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Convert `input` to `case`. Numbers are words of their own, e.g. `v2beta` → `V2Beta`
+/// and `v_2_beta`
+pub fn convert_to_case(input: &str, case: CaseStyle) -> String {
+    convert(input, case, &[])
+}
+
+/// Convert `input` to `case`, keeping numbers in the word they are written in,
+/// e.g. `v2beta` → `V2beta` and `v2beta`
+pub fn convert_to_case_preserving_numbers(input: &str, case: CaseStyle) -> String {
+    convert(input, case, &Boundary::digits())
+}
+
+fn convert(input: &str, case: CaseStyle, kept_boundaries: &[Boundary]) -> String {
+    let words = input.without_boundaries(kept_boundaries);
+    match case {
+        CaseStyle::Camel => words.to_case(Case::Camel),
+        CaseStyle::Pascal => words.to_case(Case::Pascal),
+        CaseStyle::Snake => words.to_case(Case::Snake),
+        CaseStyle::Kebab => words.to_case(Case::Kebab),
+        CaseStyle::Title => words.to_case(Case::Title),
+        CaseStyle::Lower => input.to_lowercase(),
+        CaseStyle::Upper => input.to_uppercase(),
     }
 }
 
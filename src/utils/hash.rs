@@ -0,0 +1,32 @@
+use std::hash::{Hash, Hasher};
+
+/// Hash a value, e.g. file contents or configuration, to detect changes between builds.
+/// Unlike `DefaultHasher` the result is stable across Rust releases, so it can be
+/// persisted in caches and checked-in files without churning on toolchain upgrades.
+pub fn stable_fingerprint<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Fnv1aHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// 64-bit FNV-1a
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
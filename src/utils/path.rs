@@ -1,15 +1,48 @@
 use std::collections::HashSet;
 
-pub fn extract_parameters_from_path(path: &str) -> Vec<String> {
-    let mut params = Vec::new();
+/// One `/`-separated piece of a route template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    Static(String),
+    /// `{name}`, matches exactly one segment
+    Param(String),
+    /// `{*name}`, matches the remaining segments
+    Wildcard(String),
+}
 
-    for segment in path.split('/') {
-        if segment.starts_with('{') && segment.ends_with('}') {
-            let param_name = segment[1..segment.len() - 1].to_string();
-            params.push(param_name);
-        }
+/// Split a route template such as `/files/{id}/{*rest}` into segments. The root
+/// path has no segments; a trailing slash yields a final empty static segment.
+pub fn parse_path_template(path: &str) -> Vec<PathSegment> {
+    let path = path.strip_prefix('/').unwrap_or(path);
+    if path.is_empty() {
+        return Vec::new();
     }
 
+    path.split('/')
+        .map(|segment| {
+            match segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+            {
+                Some(name) => match name.strip_prefix('*') {
+                    Some(name) => PathSegment::Wildcard(name.to_string()),
+                    None => PathSegment::Param(name.to_string()),
+                },
+                None => PathSegment::Static(segment.to_string()),
+            }
+        })
+        .collect()
+}
+
+pub fn extract_parameters_from_path(path: &str) -> Vec<String> {
+    let mut params: Vec<String> = parse_path_template(path)
+        .into_iter()
+        .filter_map(|segment| match segment {
+            PathSegment::Param(name) | PathSegment::Wildcard(name) => Some(name),
+            PathSegment::Static(_) => None,
+        })
+        .collect();
+
     // Remove duplicates while preserving order
     let mut seen = HashSet::new();
     params.retain(|param| seen.insert(param.clone()));
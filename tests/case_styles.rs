@@ -0,0 +1,94 @@
+use route_info_builder::{
+    CaseStyle, Config, NamingConfig, TypeScriptConfig, generate_links, generate_ts_client,
+    generate_ts_hooks,
+};
+use std::path::Path;
+
+mod common;
+use common::{FIXTURES, TempDir};
+
+#[test]
+fn case_styles_deserialize_from_config_strings() {
+    let naming: NamingConfig =
+        serde_json::from_str(r#"{"variant_case": "PascalCase", "field_case": "snake"}"#).unwrap();
+    assert_eq!(naming.variant_case, Some(CaseStyle::Pascal));
+    assert_eq!(naming.field_case, Some(CaseStyle::Snake));
+
+    let error = serde_json::from_str::<NamingConfig>(r#"{"variant_case": "SpongeCase"}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Unknown case 'SpongeCase'"), "{error}");
+}
+
+#[test]
+fn preserve_numbers_decides_whether_numbers_are_words() {
+    let dir = TempDir::with_controllers(
+        "numbers",
+        &[(
+            "oauth.rs",
+            r#"
+pub async fn callback(Path(v2id): Path<String>) -> Result<Response> {
+    format::empty()
+}
+
+pub fn routes() -> Routes {
+    Routes::new()
+        .prefix("api/v2beta")
+        .add("/oauth2/{v2id}", get(callback))
+}
+"#,
+        )],
+    );
+    let generate = |preserve_numbers| {
+        let config = Config {
+            controllers_path: dir.path().to_path_buf(),
+            naming: NamingConfig {
+                preserve_numbers,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        (
+            generate_links(&config).unwrap().rust_code,
+            generate_ts_client(&config).unwrap(),
+        )
+    };
+
+    let (rust, typescript) = generate(None);
+    assert!(
+        rust.contains("GetApiV2BetaOauth2V2Id { v_2_id: String }"),
+        "{rust}"
+    );
+    assert!(typescript.contains("getApiV2BetaOauth2V2Id: async ("));
+
+    // Only Rust names keep numbers in their words
+    let (rust, typescript) = generate(Some(true));
+    assert!(
+        rust.contains("GetApiV2betaOauth2V2id { v2id: String }"),
+        "{rust}"
+    );
+    assert!(typescript.contains("getApiV2BetaOauth2V2Id: async ("));
+}
+
+#[test]
+fn typescript_names_follow_case_settings() {
+    let config = Config {
+        controllers_path: Path::new(FIXTURES).to_path_buf(),
+        typescript: TypeScriptConfig {
+            method_case: Some(CaseStyle::Snake),
+            hook_case: Some(CaseStyle::Snake),
+            interface_case: Some(CaseStyle::Snake),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let client = generate_ts_client(&config).unwrap();
+    let hooks = generate_ts_hooks(&config).unwrap();
+
+    assert!(client.contains("get_api_users_user_id: async ("));
+    assert!(client.contains("params: get_api_users_user_id_params"));
+    assert!(client.contains("export interface get_api_users_user_id_params"));
+    assert!(hooks.contains("export function use_get_api_users_user_id("));
+    assert!(hooks.contains("client.get_api_users_user_id(params"));
+    assert!(!client.contains("getApiUsersUserId"));
+}
@@ -0,0 +1,62 @@
+use route_info_builder::{
+    Config, RustConfig, TypeScriptConfig, WriteStatus, check_links, generate_links,
+};
+use std::fs;
+use std::path::Path;
+
+mod common;
+use common::{FIXTURES, TempDir};
+
+#[test]
+fn check_mode_reports_stale_and_missing_files_without_writing() {
+    let dir = TempDir::new("check-mode");
+    let links = dir.join("links.rs");
+    let hooks = dir.join("ts/api.ts");
+    let config = Config {
+        controllers_path: Path::new(FIXTURES).to_path_buf(),
+        rust: RustConfig {
+            output_path: Some(links.clone()),
+            ..Default::default()
+        },
+        typescript: TypeScriptConfig {
+            generate_client: Some(true),
+            output_path: Some(dir.join("ts")),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    generate_links(&config).unwrap();
+    let result = check_links(&config).unwrap();
+    assert!(!result.files.is_empty());
+    assert!(
+        result
+            .files
+            .iter()
+            .all(|file| file.status == WriteStatus::Checked)
+    );
+    assert_eq!(result.changed_files().count(), 0);
+
+    let generated = fs::read_to_string(&links).unwrap();
+    let edited = generated.replacen("pub enum Link", "pub enum Stale", 1);
+    fs::write(&links, &edited).unwrap();
+    fs::remove_file(&hooks).unwrap();
+
+    let error = check_links(&config).unwrap_err().to_string();
+    assert!(
+        error.starts_with("2 generated file(s) are stale"),
+        "{error}"
+    );
+    assert!(error.contains(&format!("--- {}\n", links.display())));
+    assert!(error.contains(&format!("+++ {} (generated)\n", links.display())));
+    assert!(error.contains("\n-pub enum Stale"));
+    assert!(error.contains("\n+pub enum Link"));
+    assert!(error.contains(&format!(
+        "--- /dev/null\n+++ {} (generated)\n",
+        hooks.display()
+    )));
+
+    // Nothing is written in check mode
+    assert_eq!(fs::read_to_string(&links).unwrap(), edited);
+    assert!(!hooks.exists());
+}
@@ -0,0 +1,75 @@
+//! Fixtures shared by the integration tests
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Controllers every generator test runs against
+pub const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/controllers");
+
+/// Fresh directory under the system temp dir, removed on drop even when the test panics
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "route-info-builder-{}-{}-{}",
+            name,
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+
+    /// Directory holding the given `(file name, source)` controllers
+    pub fn with_controllers(name: &str, controllers: &[(&str, &str)]) -> Self {
+        let dir = Self::new(name);
+        for (file, source) in controllers {
+            dir.write(file, source);
+        }
+        dir
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    pub fn join(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.0.join(path)
+    }
+
+    /// Write a file relative to the directory, creating parent directories
+    pub fn write(&self, file: impl AsRef<Path>, content: &str) {
+        let path = self.0.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(path, content).unwrap();
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Compare generated code with `tests/fixtures/<fixture>`, rewriting the fixture
+/// instead when `UPDATE_FIXTURES` is set
+pub fn assert_fixture(fixture: &str, code: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(fixture);
+    if std::env::var_os("UPDATE_FIXTURES").is_some() {
+        fs::write(&path, code).unwrap();
+    }
+    let on_disk = fs::read_to_string(&path).unwrap();
+    assert!(
+        on_disk == code,
+        "tests/fixtures/{fixture} is stale, rerun with UPDATE_FIXTURES=1"
+    );
+}
@@ -0,0 +1,126 @@
+use route_info_builder::{
+    CaseStyle, CollisionStrategy, Config, GroupBy, NamingStrategy, QuoteStyle, RouteOrder,
+    RustLayout, TemplateEngine, TypeScriptConfig, TypeScriptFormatConfig, generate_ts_client,
+};
+use serde::{Serialize, de::DeserializeOwned};
+use std::path::Path;
+
+mod common;
+use common::FIXTURES;
+
+/// Parse a config string into `T` and serialize it back
+fn round_trip<T: DeserializeOwned + Serialize>(value: &str) -> Result<String, String> {
+    let parsed: T = serde_json::from_value(value.into()).map_err(|e| e.to_string())?;
+    Ok(serde_json::to_value(parsed)
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string())
+}
+
+/// Setting, its parser, every accepted string, an invalid string and the expected error
+type EnumSetting = (
+    &'static str,
+    fn(&str) -> Result<String, String>,
+    &'static [&'static str],
+    &'static str,
+    &'static str,
+);
+
+#[test]
+fn enum_settings_deserialize_from_config_strings() {
+    let settings: &[EnumSetting] = &[
+        (
+            "naming.variant_case",
+            round_trip::<CaseStyle>,
+            &[
+                "camelCase",
+                "PascalCase",
+                "snake_case",
+                "kebab-case",
+                "title_case",
+                "lowercase",
+                "UPPERCASE",
+            ],
+            "sponge",
+            "Unknown case 'sponge'",
+        ),
+        (
+            "naming.strategy",
+            round_trip::<NamingStrategy>,
+            &["path", "rest", "handler"],
+            "semantic",
+            "unknown variant `semantic`, expected one of `path`, `rest`, `handler`",
+        ),
+        (
+            "naming.collision_strategy",
+            round_trip::<CollisionStrategy>,
+            &["error", "method", "controller", "number"],
+            "random",
+            "unknown variant `random`, expected one of `error`, `method`, `controller`, `number`",
+        ),
+        (
+            "route_order",
+            round_trip::<RouteOrder>,
+            &["declaration", "path", "name", "controller"],
+            "random",
+            "unknown variant `random`, expected one of `declaration`, `path`, `name`, `controller`",
+        ),
+        (
+            "typescript.format.quote_style",
+            round_trip::<QuoteStyle>,
+            &["double", "single"],
+            "backtick",
+            "unknown variant `backtick`, expected `double` or `single`",
+        ),
+        (
+            "rust.layout",
+            round_trip::<RustLayout>,
+            &["single", "per_controller"],
+            "nested",
+            "unknown variant `nested`, expected `single` or `per_controller`",
+        ),
+        (
+            "rust.group_by",
+            round_trip::<GroupBy>,
+            &["controller", "path_prefix"],
+            "prefix",
+            "unknown variant `prefix`, expected `controller` or `path_prefix`",
+        ),
+        (
+            "rust.template_engines",
+            round_trip::<TemplateEngine>,
+            &["tera", "minijinja", "askama"],
+            "jinja",
+            "unknown variant `jinja`, expected one of `tera`, `minijinja`, `askama`",
+        ),
+    ];
+
+    for (setting, round_trip, accepted, invalid, expected) in settings {
+        for value in *accepted {
+            assert_eq!(round_trip(value).as_deref(), Ok(*value), "{setting}");
+        }
+        let error = round_trip(invalid).unwrap_err();
+        assert!(error.contains(expected), "{setting}: {error}");
+    }
+}
+
+#[test]
+fn quote_style_applies_to_generated_typescript() {
+    let client = |quote_style| {
+        generate_ts_client(&Config {
+            controllers_path: Path::new(FIXTURES).to_path_buf(),
+            typescript: TypeScriptConfig {
+                format: TypeScriptFormatConfig {
+                    quote_style,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .unwrap()
+    };
+    assert!(client(None).contains("method: \"GET\""));
+    assert!(client(Some(QuoteStyle::Single)).contains("method: 'GET'"));
+}
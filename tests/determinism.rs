@@ -0,0 +1,171 @@
+use route_info_builder::{
+    Config, NamingConfig, RouteOrder, RustConfig, TypeScriptConfig, generate_links,
+    generate_ts_client, generate_ts_hooks, generate_ts_types, generate_zod_schemas,
+    scan_controllers_folder,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+mod common;
+use common::{FIXTURES, TempDir};
+
+fn config_for(controllers_path: &Path) -> Config {
+    Config {
+        controllers_path: controllers_path.to_path_buf(),
+        typescript: TypeScriptConfig {
+            generate_types: Some(true),
+            generate_zod_schemas: Some(true),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Every generated artifact, concatenated
+fn generate_all(config: &Config) -> String {
+    [
+        generate_links(config).unwrap().rust_code,
+        generate_ts_client(config).unwrap(),
+        generate_ts_hooks(config).unwrap(),
+        generate_ts_types(config).unwrap(),
+        generate_zod_schemas(config).unwrap(),
+    ]
+    .join("\n---\n")
+}
+
+/// Copy the fixtures into a fresh directory, creating files in the given order
+fn copy_fixtures(name: &str, reverse: bool) -> TempDir {
+    let dir = TempDir::new(name);
+    let mut files: Vec<PathBuf> = fs::read_dir(FIXTURES)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    files.sort();
+    if reverse {
+        files.reverse();
+    }
+    for file in files {
+        fs::copy(&file, dir.join(file.file_name().unwrap())).unwrap();
+    }
+    dir
+}
+
+#[test]
+fn output_is_identical_across_runs() {
+    let config = config_for(Path::new(FIXTURES));
+    assert_eq!(generate_all(&config), generate_all(&config));
+}
+
+#[test]
+fn output_does_not_depend_on_file_creation_order() {
+    let forward = copy_fixtures("forward", false);
+    let reverse = copy_fixtures("reverse", true);
+
+    assert_eq!(
+        generate_all(&config_for(forward.path())),
+        generate_all(&config_for(reverse.path()))
+    );
+}
+
+/// Config writing every output under `dir`, with controllers copied into it
+fn config_in(dir: &TempDir) -> Config {
+    let controllers = dir.join("controllers");
+    fs::create_dir_all(&controllers).unwrap();
+    for entry in fs::read_dir(FIXTURES).unwrap() {
+        let file = entry.unwrap().path();
+        fs::copy(&file, controllers.join(file.file_name().unwrap())).unwrap();
+    }
+    Config {
+        controllers_path: controllers,
+        typescript: TypeScriptConfig {
+            output_path: Some(dir.join("frontend")),
+            dto_paths: Some(vec![dir.join("dto")]),
+            ..Default::default()
+        },
+        rust: RustConfig {
+            output_path: Some(dir.join("src/links.rs")),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn generated_header_does_not_depend_on_paths() {
+    let first = TempDir::new("header-first");
+    let second = TempDir::new("header-second");
+    let header = |config: &Config| {
+        generate_links(config).unwrap();
+        let links = fs::read_to_string(config.rust.output_path.as_ref().unwrap()).unwrap();
+        links.lines().next().unwrap().to_string()
+    };
+
+    let config = config_in(&first);
+    assert!(header(&config).contains(&config.fingerprint()));
+    assert_eq!(header(&config), header(&config_in(&second)));
+
+    let renamed = Config {
+        naming: NamingConfig {
+            variant_prefix: Some("Api".to_string()),
+            ..Default::default()
+        },
+        ..config_in(&second)
+    };
+    assert_ne!(config.fingerprint(), renamed.fingerprint());
+}
+
+#[test]
+fn rust_code_matches_the_written_links_file() {
+    let dir = TempDir::new("rust-code");
+    let config = config_in(&dir);
+    let result = generate_links(&config).unwrap();
+
+    let links = fs::read_to_string(config.rust.output_path.as_ref().unwrap()).unwrap();
+    let (header, code) = links.split_once("\n\n").unwrap();
+    assert!(header.starts_with("// @generated"));
+    assert_eq!(result.rust_code, code);
+
+    let without_output = generate_links(&config_for(Path::new(FIXTURES))).unwrap();
+    assert_eq!(without_output.rust_code, result.rust_code);
+}
+
+#[test]
+fn routes_follow_configured_order() {
+    let mut config = config_for(Path::new(FIXTURES));
+
+    let declared: Vec<(String, String)> = scan_controllers_folder(&config)
+        .unwrap()
+        .into_iter()
+        .map(|route| (route.method, route.path))
+        .collect();
+    assert_eq!(
+        declared[0],
+        ("POST".to_string(), "/api/auth/login".to_string())
+    );
+
+    config.route_order = Some(RouteOrder::Path);
+    let by_path: Vec<(String, String)> = scan_controllers_folder(&config)
+        .unwrap()
+        .into_iter()
+        .map(|route| (route.path, route.method))
+        .collect();
+    let mut sorted = by_path.clone();
+    sorted.sort();
+    assert_eq!(by_path, sorted);
+
+    config.route_order = Some(RouteOrder::Name);
+    let names: Vec<String> = scan_controllers_folder(&config)
+        .unwrap()
+        .into_iter()
+        .map(|route| route.name)
+        .collect();
+    assert!(names.windows(2).all(|pair| pair[0] <= pair[1]));
+
+    config.route_order = Some(RouteOrder::Controller);
+    let controllers: Vec<String> = scan_controllers_folder(&config)
+        .unwrap()
+        .into_iter()
+        .map(|route| route.controller)
+        .collect();
+    assert!(controllers.windows(2).all(|pair| pair[0] <= pair[1]));
+}
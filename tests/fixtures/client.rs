@@ -0,0 +1,231 @@
+/// Error body sent by the server
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct RawApiError {
+    pub error: String,
+    #[serde(default)]
+    pub description: String,
+}
+/// Structured details carried in the description of Bad Request errors
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct ErrorDetails {
+    pub code: String,
+    pub message: String,
+}
+impl RawApiError {
+    /// Details parsed from `description`, if it holds any
+    pub fn details(&self) -> Option<ErrorDetails> {
+        serde_json::from_str(&self.description).ok()
+    }
+}
+/// Errors a route is known to return, recognised by their error code
+pub trait RouteError: Sized {
+    fn from_code(code: &str) -> Option<Self>;
+}
+impl RouteError for std::convert::Infallible {
+    fn from_code(_code: &str) -> Option<Self> {
+        None
+    }
+}
+/// Failure of a client call
+#[derive(Debug)]
+pub enum ClientError<E = std::convert::Infallible> {
+    /// The request could not be sent or the response could not be read
+    Transport(reqwest::Error),
+    /// The response body did not match the expected type
+    Decode(serde_json::Error),
+    /// One of the errors the route is known to return
+    Route { status: reqwest::StatusCode, error: E },
+    /// Any other error response
+    Api { status: reqwest::StatusCode, error: Option<RawApiError> },
+}
+impl<E> From<reqwest::Error> for ClientError<E> {
+    fn from(error: reqwest::Error) -> Self {
+        ClientError::Transport(error)
+    }
+}
+impl<E: std::fmt::Debug> std::fmt::Display for ClientError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Transport(error) => write!(f, "request failed: {error}"),
+            ClientError::Decode(error) => write!(f, "invalid response body: {error}"),
+            ClientError::Route { status, error } => write!(f, "{status}: {error:?}"),
+            ClientError::Api { status, error: Some(error) } => {
+                write!(f, "{status}: {} {}", error.error, error.description)
+            }
+            ClientError::Api { status, error: None } => write!(f, "{status}"),
+        }
+    }
+}
+impl<E: std::fmt::Debug> std::error::Error for ClientError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Transport(error) => Some(error),
+            ClientError::Decode(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+/// Typed client for every application route
+#[derive(Debug, Clone)]
+pub struct ApiClient {
+    http: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+}
+impl ApiClient {
+    /// Client for the API served at `base_url`, e.g. `https://example.com`
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(reqwest::Client::new(), base_url)
+    }
+    /// Client reusing an existing `reqwest::Client`
+    pub fn with_client(http: reqwest::Client, base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Self {
+            http,
+            base_url,
+            token: None,
+        }
+    }
+    /// Bearer token sent to routes that require authentication
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+    /// Replace or clear the bearer token
+    pub fn set_token(&mut self, token: Option<String>) {
+        self.token = token;
+    }
+    async fn send<T, E>(
+        &self,
+        request: reqwest::RequestBuilder,
+        requires_auth: bool,
+    ) -> Result<T, ClientError<E>>
+    where
+        T: serde::de::DeserializeOwned,
+        E: RouteError,
+    {
+        let request = match &self.token {
+            Some(token) if requires_auth => request.bearer_auth(token),
+            _ => request,
+        };
+        let response = request.send().await?;
+        let status = response.status();
+        let bytes = response.bytes().await?;
+        if !status.is_success() {
+            let error = serde_json::from_slice::<RawApiError>(&bytes).ok();
+            let route_error = error
+                .as_ref()
+                .and_then(|error| error.details())
+                .and_then(|details| E::from_code(&details.code));
+            return Err(
+                match route_error {
+                    Some(error) => {
+                        ClientError::Route {
+                            status,
+                            error,
+                        }
+                    }
+                    None => ClientError::Api { status, error },
+                },
+            );
+        }
+        let body: &[u8] = if bytes.is_empty() { b"null" } else { &bytes };
+        serde_json::from_slice(body).map_err(ClientError::Decode)
+    }
+    /// `POST /api/auth/login`
+    pub async fn post_api_auth_login(
+        &self,
+    ) -> Result<serde_json::Value, ClientError<std::convert::Infallible>> {
+        let request = self
+            .http
+            .request(reqwest::Method::POST, format!("{}/api/auth/login", self.base_url));
+        self.send(request, false).await
+    }
+    /// `POST /api/auth/logout`
+    pub async fn post_api_auth_logout(
+        &self,
+    ) -> Result<serde_json::Value, ClientError<std::convert::Infallible>> {
+        let request = self
+            .http
+            .request(
+                reqwest::Method::POST,
+                format!("{}/api/auth/logout", self.base_url),
+            );
+        self.send(request, true).await
+    }
+    /// `GET /api/posts/`
+    pub async fn get_api_posts(
+        &self,
+    ) -> Result<Vec<crate::dto::PostResponse>, ClientError<std::convert::Infallible>> {
+        let request = self
+            .http
+            .request(reqwest::Method::GET, format!("{}/api/posts/", self.base_url));
+        self.send(request, false).await
+    }
+    /// `POST /api/posts/{post_id}/comments`
+    pub async fn post_api_posts_post_id_comments(
+        &self,
+        post_id: i32,
+        body: &crate::dto::CommentParams,
+    ) -> Result<serde_json::Value, ClientError<std::convert::Infallible>> {
+        let request = self
+            .http
+            .request(
+                reqwest::Method::POST,
+                format!("{}/api/posts/{}/comments", self.base_url, post_id),
+            );
+        let request = request.json(body);
+        self.send(request, true).await
+    }
+    /// `GET /api/users/`
+    pub async fn get_api_users(
+        &self,
+        query: Option<&crate::dto::ListQuery>,
+    ) -> Result<Vec<crate::dto::UserResponse>, ClientError<std::convert::Infallible>> {
+        let request = self
+            .http
+            .request(reqwest::Method::GET, format!("{}/api/users/", self.base_url));
+        let request = match query {
+            Some(query) => request.query(query),
+            None => request,
+        };
+        self.send(request, false).await
+    }
+    /// `GET /api/users/{user_id}`
+    pub async fn get_api_users_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<crate::dto::UserResponse, ClientError<std::convert::Infallible>> {
+        let request = self
+            .http
+            .request(
+                reqwest::Method::GET,
+                format!("{}/api/users/{}", self.base_url, user_id),
+            );
+        self.send(request, false).await
+    }
+    /// `POST /api/users/`
+    pub async fn post_api_users(
+        &self,
+        body: &crate::dto::CreateUserParams,
+    ) -> Result<crate::dto::UserResponse, ClientError<std::convert::Infallible>> {
+        let request = self
+            .http
+            .request(reqwest::Method::POST, format!("{}/api/users/", self.base_url));
+        let request = request.json(body);
+        self.send(request, true).await
+    }
+    /// `DELETE /api/users/{user_id}`
+    pub async fn delete_api_users_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<serde_json::Value, ClientError<std::convert::Infallible>> {
+        let request = self
+            .http
+            .request(
+                reqwest::Method::DELETE,
+                format!("{}/api/users/{}", self.base_url, user_id),
+            );
+        self.send(request, true).await
+    }
+}
@@ -0,0 +1,16 @@
+use loco_rs::prelude::*;
+
+pub async fn login() -> Result<Response> {
+    format::empty()
+}
+
+pub async fn logout(auth: auth::JWT) -> Result<Response> {
+    format::empty()
+}
+
+pub fn routes() -> Routes {
+    Routes::new()
+        .prefix("api/auth")
+        .add("/login", post(login))
+        .add("/logout", post(logout))
+}
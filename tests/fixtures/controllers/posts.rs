@@ -0,0 +1,31 @@
+use loco_rs::prelude::*;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CommentParams {
+    pub body: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PostResponse {
+    pub id: i32,
+    pub title: String,
+}
+
+pub async fn list() -> Result<Response> {
+    format::json(Vec::<PostResponse>::new())
+}
+
+pub async fn add_comment(
+    auth: auth::JWT,
+    Path(post_id): Path<i32>,
+    Json(params): Json<CommentParams>,
+) -> Result<Response> {
+    format::empty()
+}
+
+pub fn routes() -> Routes {
+    Routes::new()
+        .prefix("api/posts")
+        .add("/", get(list))
+        .add("/{post_id}/comments", post(add_comment))
+}
@@ -0,0 +1,44 @@
+use loco_rs::prelude::*;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateUserParams {
+    pub display_name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListQuery {
+    pub page: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserResponse {
+    pub id: i32,
+    pub name: String,
+}
+
+pub async fn list(Query(query): Query<ListQuery>) -> Result<Response> {
+    format::json(Vec::<UserResponse>::new())
+}
+
+pub async fn get_one(Path(user_id): Path<i32>) -> Result<Response> {
+    format::json(UserResponse::default())
+}
+
+pub async fn create(auth: auth::JWT, Json(params): Json<CreateUserParams>) -> Result<Response> {
+    format::json(UserResponse::default())
+}
+
+pub async fn remove(auth: auth::JWT, Path(user_id): Path<i32>) -> Result<Response> {
+    format::empty()
+}
+
+pub fn routes() -> Routes {
+    Routes::new()
+        .prefix("api/users")
+        .add("/", get(list))
+        .add("/{user_id}", get(get_one))
+        .add("/", post(create))
+        .add("/{user_id}", delete(remove))
+}
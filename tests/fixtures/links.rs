@@ -0,0 +1,350 @@
+/// Auto-generated link enum for all application routes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Link {
+    PostApiAuthLogin,
+    PostApiAuthLogout,
+    GetApiPosts,
+    PostApiPostsPostIdComments { post_id: String },
+    GetApiUsers,
+    GetApiUsersUserId { user_id: String },
+    PostApiUsers,
+    DeleteApiUsersUserId { user_id: String },
+}
+impl Link {
+    /// Convert the link to a URL path; borrowed for routes without path parameters
+    pub fn to_path(&self) -> std::borrow::Cow<'static, str> {
+        match self {
+            Link::PostApiAuthLogin => std::borrow::Cow::Borrowed("/api/auth/login"),
+            Link::PostApiAuthLogout => std::borrow::Cow::Borrowed("/api/auth/logout"),
+            Link::GetApiPosts => std::borrow::Cow::Borrowed("/api/posts/"),
+            Link::GetApiUsers => std::borrow::Cow::Borrowed("/api/users/"),
+            Link::PostApiUsers => std::borrow::Cow::Borrowed("/api/users/"),
+            _ => {
+                let mut path = String::with_capacity(self.meta().template.len());
+                self.write_path(&mut path).expect("writing to a String cannot fail");
+                std::borrow::Cow::Owned(path)
+            }
+        }
+    }
+    /// Write the URL path into `w` without intermediate allocations
+    pub fn write_path<W: std::fmt::Write + ?Sized>(
+        &self,
+        w: &mut W,
+    ) -> std::fmt::Result {
+        match self {
+            Link::PostApiAuthLogin => w.write_str("/api/auth/login"),
+            Link::PostApiAuthLogout => w.write_str("/api/auth/logout"),
+            Link::GetApiPosts => w.write_str("/api/posts/"),
+            Link::PostApiPostsPostIdComments { post_id } => {
+                w.write_str("/api/posts/")?;
+                write_path_segment(w, post_id)?;
+                w.write_str("/comments")
+            }
+            Link::GetApiUsers => w.write_str("/api/users/"),
+            Link::GetApiUsersUserId { user_id } => {
+                w.write_str("/api/users/")?;
+                write_path_segment(w, user_id)
+            }
+            Link::PostApiUsers => w.write_str("/api/users/"),
+            Link::DeleteApiUsersUserId { user_id } => {
+                w.write_str("/api/users/")?;
+                write_path_segment(w, user_id)
+            }
+        }
+    }
+    /// Path including the query string; no route takes query parameters
+    pub fn to_path_and_query(&self) -> String {
+        self.to_path().into_owned()
+    }
+    /// Absolute URL against the given base, e.g. `https://example.com`
+    pub fn to_url_with_base(&self, base_url: &str) -> String {
+        format!("{}{}", base_url.trim_end_matches('/'), self.to_path_and_query())
+    }
+    /// Get the HTTP method for this route
+    pub fn method(&self) -> &'static str {
+        match self {
+            Link::PostApiAuthLogin => "POST",
+            Link::PostApiAuthLogout => "POST",
+            Link::GetApiPosts => "GET",
+            Link::PostApiPostsPostIdComments { .. } => "POST",
+            Link::GetApiUsers => "GET",
+            Link::GetApiUsersUserId { .. } => "GET",
+            Link::PostApiUsers => "POST",
+            Link::DeleteApiUsersUserId { .. } => "DELETE",
+        }
+    }
+    /// Static metadata of this link's route
+    pub fn meta(&self) -> &'static RouteMeta {
+        match self {
+            Link::PostApiAuthLogin => &ROUTES[0usize],
+            Link::PostApiAuthLogout => &ROUTES[1usize],
+            Link::GetApiPosts => &ROUTES[2usize],
+            Link::PostApiPostsPostIdComments { .. } => &ROUTES[3usize],
+            Link::GetApiUsers => &ROUTES[4usize],
+            Link::GetApiUsersUserId { .. } => &ROUTES[5usize],
+            Link::PostApiUsers => &ROUTES[6usize],
+            Link::DeleteApiUsersUserId { .. } => &ROUTES[7usize],
+        }
+    }
+    /// Every distinct path template, in generated route order (see `route_order`)
+    pub const ALL_TEMPLATES: &'static [&'static str] = &[
+        "/api/auth/login",
+        "/api/auth/logout",
+        "/api/posts/",
+        "/api/posts/{post_id}/comments",
+        "/api/users/",
+        "/api/users/{user_id}",
+    ];
+    /// Variant name, e.g. `GetUser`, or `Users::Get` with groups
+    pub fn name(&self) -> &'static str {
+        self.meta().name
+    }
+    /// Path pattern with `{param}` placeholders
+    pub fn template(&self) -> &'static str {
+        self.meta().template
+    }
+    /// Whether the handler requires authentication
+    pub fn requires_auth(&self) -> bool {
+        self.meta().requires_auth
+    }
+    /// Handler function name
+    pub fn handler(&self) -> &'static str {
+        self.meta().handler
+    }
+    /// Controller module the route is declared in
+    pub fn controller(&self) -> &'static str {
+        self.meta().controller
+    }
+    /// Parse a concrete path such as `/users/42` back into its link
+    pub fn from_path(method: &str, path: &str) -> Option<Link> {
+        let path = path.strip_prefix('/')?;
+        let segments: Vec<&str> = if path.is_empty() {
+            Vec::new()
+        } else {
+            path.split('/').collect()
+        };
+        let rest = segments.as_slice();
+        if let Some((&"api", tail)) = rest.split_first() {
+            let rest = tail;
+            if let Some((&"auth", tail)) = rest.split_first() {
+                let rest = tail;
+                if let Some((&"login", tail)) = rest.split_first() {
+                    let rest = tail;
+                    if rest.is_empty() {
+                        if method == "POST" {
+                            return Some(Link::PostApiAuthLogin);
+                        }
+                        return None;
+                    }
+                }
+                if let Some((&"logout", tail)) = rest.split_first() {
+                    let rest = tail;
+                    if rest.is_empty() {
+                        if method == "POST" {
+                            return Some(Link::PostApiAuthLogout);
+                        }
+                        return None;
+                    }
+                }
+            }
+            if let Some((&"posts", tail)) = rest.split_first() {
+                let rest = tail;
+                if let Some((&"", tail)) = rest.split_first() {
+                    let rest = tail;
+                    if rest.is_empty() {
+                        if method == "GET" {
+                            return Some(Link::GetApiPosts);
+                        }
+                        return None;
+                    }
+                }
+                if let Some((&p2, tail)) = rest
+                    .split_first()
+                    .filter(|(segment, _)| !segment.is_empty())
+                {
+                    let rest = tail;
+                    if let Some((&"comments", tail)) = rest.split_first() {
+                        let rest = tail;
+                        if rest.is_empty() {
+                            if method == "POST" {
+                                return Some(Link::PostApiPostsPostIdComments {
+                                    post_id: decode_path_segment(p2)?,
+                                });
+                            }
+                            return None;
+                        }
+                    }
+                }
+            }
+            if let Some((&"users", tail)) = rest.split_first() {
+                let rest = tail;
+                if let Some((&"", tail)) = rest.split_first() {
+                    let rest = tail;
+                    if rest.is_empty() {
+                        if method == "GET" {
+                            return Some(Link::GetApiUsers);
+                        }
+                        if method == "POST" {
+                            return Some(Link::PostApiUsers);
+                        }
+                        return None;
+                    }
+                }
+                if let Some((&p2, tail)) = rest
+                    .split_first()
+                    .filter(|(segment, _)| !segment.is_empty())
+                {
+                    let rest = tail;
+                    if rest.is_empty() {
+                        if method == "GET" {
+                            return Some(Link::GetApiUsersUserId {
+                                user_id: decode_path_segment(p2)?,
+                            });
+                        }
+                        if method == "DELETE" {
+                            return Some(Link::DeleteApiUsersUserId {
+                                user_id: decode_path_segment(p2)?,
+                            });
+                        }
+                        return None;
+                    }
+                }
+            }
+        }
+        None
+    }
+    /// Match a request target such as `/users/42?tab=posts`, ignoring the query and fragment
+    pub fn match_request(method: &str, target: &str) -> Option<Link> {
+        let path = target.split(['?', '#']).next().unwrap_or(target);
+        Self::from_path(method, path)
+    }
+}
+impl std::fmt::Display for Link {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_path(f)
+    }
+}
+/// Static description of a route, available without rescanning sources
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RouteMeta {
+    /// `Link` variant name, e.g. `GetUser`, or `Users::Get` with groups
+    pub name: &'static str,
+    pub method: &'static str,
+    /// Path pattern with `{param}` placeholders
+    pub template: &'static str,
+    pub requires_auth: bool,
+    /// Handler function name
+    pub handler: &'static str,
+    /// Controller module the route is declared in
+    pub controller: &'static str,
+}
+/// Every route of the application, in the order of the `Link` variants
+pub static ROUTES: &[RouteMeta] = &[
+    RouteMeta {
+        name: "PostApiAuthLogin",
+        method: "POST",
+        template: "/api/auth/login",
+        requires_auth: false,
+        handler: "login",
+        controller: "auth",
+    },
+    RouteMeta {
+        name: "PostApiAuthLogout",
+        method: "POST",
+        template: "/api/auth/logout",
+        requires_auth: true,
+        handler: "logout",
+        controller: "auth",
+    },
+    RouteMeta {
+        name: "GetApiPosts",
+        method: "GET",
+        template: "/api/posts/",
+        requires_auth: false,
+        handler: "list",
+        controller: "posts",
+    },
+    RouteMeta {
+        name: "PostApiPostsPostIdComments",
+        method: "POST",
+        template: "/api/posts/{post_id}/comments",
+        requires_auth: true,
+        handler: "add_comment",
+        controller: "posts",
+    },
+    RouteMeta {
+        name: "GetApiUsers",
+        method: "GET",
+        template: "/api/users/",
+        requires_auth: false,
+        handler: "list",
+        controller: "users",
+    },
+    RouteMeta {
+        name: "GetApiUsersUserId",
+        method: "GET",
+        template: "/api/users/{user_id}",
+        requires_auth: false,
+        handler: "get_one",
+        controller: "users",
+    },
+    RouteMeta {
+        name: "PostApiUsers",
+        method: "POST",
+        template: "/api/users/",
+        requires_auth: true,
+        handler: "create",
+        controller: "users",
+    },
+    RouteMeta {
+        name: "DeleteApiUsersUserId",
+        method: "DELETE",
+        template: "/api/users/{user_id}",
+        requires_auth: true,
+        handler: "remove",
+        controller: "users",
+    },
+];
+/// Write a path segment percent-encoded like JavaScript's `encodeURIComponent`
+fn write_path_segment<W: std::fmt::Write + ?Sized>(
+    w: &mut W,
+    value: &str,
+) -> std::fmt::Result {
+    fn is_unreserved(byte: u8) -> bool {
+        byte.is_ascii_alphanumeric() || b"-_.!~*'()".contains(&byte)
+    }
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    let bytes = value.as_bytes();
+    let mut start = 0;
+    while start < bytes.len() {
+        let end = bytes[start..]
+            .iter()
+            .position(|&byte| !is_unreserved(byte))
+            .map_or(bytes.len(), |offset| start + offset);
+        w.write_str(&value[start..end])?;
+        start = end;
+        while start < bytes.len() && !is_unreserved(bytes[start]) {
+            let byte = bytes[start];
+            w.write_char('%')?;
+            w.write_char(HEX[(byte >> 4) as usize] as char)?;
+            w.write_char(HEX[(byte & 0x0f) as usize] as char)?;
+            start += 1;
+        }
+    }
+    Ok(())
+}
+/// Decode a percent-encoded path segment; `None` if it is malformed or not UTF-8
+fn decode_path_segment(segment: &str) -> Option<String> {
+    let mut decoded = Vec::with_capacity(segment.len());
+    let mut bytes = segment.bytes();
+    while let Some(byte) = bytes.next() {
+        if byte == b'%' {
+            let high = (bytes.next()? as char).to_digit(16)?;
+            let low = (bytes.next()? as char).to_digit(16)?;
+            decoded.push((high * 16 + low) as u8);
+        } else {
+            decoded.push(byte);
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
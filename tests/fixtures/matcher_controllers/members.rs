@@ -0,0 +1,31 @@
+use loco_rs::prelude::*;
+
+pub async fn me() -> Result<Response> {
+    format::empty()
+}
+
+pub async fn show(Path(member_id): Path<String>) -> Result<Response> {
+    format::empty()
+}
+
+pub async fn remove(Path(member_id): Path<String>) -> Result<Response> {
+    format::empty()
+}
+
+pub async fn files(Path((member_id, path)): Path<(String, String)>) -> Result<Response> {
+    format::empty()
+}
+
+pub async fn teams() -> Result<Response> {
+    format::empty()
+}
+
+pub fn routes() -> Routes {
+    Routes::new()
+        .prefix("api")
+        .add("/members/me", get(me))
+        .add("/members/{member_id}", get(show))
+        .add("/members/{member_id}", delete(remove))
+        .add("/members/{member_id}/files/{*path}", get(files))
+        .add("/teams/", get(teams))
+}
@@ -0,0 +1,303 @@
+/// Auto-generated link enum for all application routes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Link {
+    GetApiMembersMe,
+    GetApiMembersMemberId { member_id: String },
+    DeleteApiMembersMemberId { member_id: String },
+    GetApiMembersMemberIdFilesPath { member_id: String, path: String },
+    GetApiTeams,
+}
+impl Link {
+    /// Convert the link to a URL path; borrowed for routes without path parameters
+    pub fn to_path(&self) -> std::borrow::Cow<'static, str> {
+        match self {
+            Link::GetApiMembersMe => std::borrow::Cow::Borrowed("/api/members/me"),
+            Link::GetApiTeams => std::borrow::Cow::Borrowed("/api/teams/"),
+            _ => {
+                let mut path = String::with_capacity(self.meta().template.len());
+                self.write_path(&mut path).expect("writing to a String cannot fail");
+                std::borrow::Cow::Owned(path)
+            }
+        }
+    }
+    /// Write the URL path into `w` without intermediate allocations
+    pub fn write_path<W: std::fmt::Write + ?Sized>(
+        &self,
+        w: &mut W,
+    ) -> std::fmt::Result {
+        match self {
+            Link::GetApiMembersMe => w.write_str("/api/members/me"),
+            Link::GetApiMembersMemberId { member_id } => {
+                w.write_str("/api/members/")?;
+                write_path_segment(w, member_id)
+            }
+            Link::DeleteApiMembersMemberId { member_id } => {
+                w.write_str("/api/members/")?;
+                write_path_segment(w, member_id)
+            }
+            Link::GetApiMembersMemberIdFilesPath { member_id, path } => {
+                w.write_str("/api/members/")?;
+                write_path_segment(w, member_id)?;
+                w.write_str("/files/")?;
+                write_path_segments(w, path)
+            }
+            Link::GetApiTeams => w.write_str("/api/teams/"),
+        }
+    }
+    /// Path including the query string; no route takes query parameters
+    pub fn to_path_and_query(&self) -> String {
+        self.to_path().into_owned()
+    }
+    /// Absolute URL against the given base, e.g. `https://example.com`
+    pub fn to_url_with_base(&self, base_url: &str) -> String {
+        format!("{}{}", base_url.trim_end_matches('/'), self.to_path_and_query())
+    }
+    /// Get the HTTP method for this route
+    pub fn method(&self) -> &'static str {
+        match self {
+            Link::GetApiMembersMe => "GET",
+            Link::GetApiMembersMemberId { .. } => "GET",
+            Link::DeleteApiMembersMemberId { .. } => "DELETE",
+            Link::GetApiMembersMemberIdFilesPath { .. } => "GET",
+            Link::GetApiTeams => "GET",
+        }
+    }
+    /// Static metadata of this link's route
+    pub fn meta(&self) -> &'static RouteMeta {
+        match self {
+            Link::GetApiMembersMe => &ROUTES[0usize],
+            Link::GetApiMembersMemberId { .. } => &ROUTES[1usize],
+            Link::DeleteApiMembersMemberId { .. } => &ROUTES[2usize],
+            Link::GetApiMembersMemberIdFilesPath { .. } => &ROUTES[3usize],
+            Link::GetApiTeams => &ROUTES[4usize],
+        }
+    }
+    /// Every distinct path template, in generated route order (see `route_order`)
+    pub const ALL_TEMPLATES: &'static [&'static str] = &[
+        "/api/members/me",
+        "/api/members/{member_id}",
+        "/api/members/{member_id}/files/{*path}",
+        "/api/teams/",
+    ];
+    /// Variant name, e.g. `GetUser`, or `Users::Get` with groups
+    pub fn name(&self) -> &'static str {
+        self.meta().name
+    }
+    /// Path pattern with `{param}` placeholders
+    pub fn template(&self) -> &'static str {
+        self.meta().template
+    }
+    /// Whether the handler requires authentication
+    pub fn requires_auth(&self) -> bool {
+        self.meta().requires_auth
+    }
+    /// Handler function name
+    pub fn handler(&self) -> &'static str {
+        self.meta().handler
+    }
+    /// Controller module the route is declared in
+    pub fn controller(&self) -> &'static str {
+        self.meta().controller
+    }
+    /// Parse a concrete path such as `/users/42` back into its link
+    pub fn from_path(method: &str, path: &str) -> Option<Link> {
+        let path = path.strip_prefix('/')?;
+        let segments: Vec<&str> = if path.is_empty() {
+            Vec::new()
+        } else {
+            path.split('/').collect()
+        };
+        let rest = segments.as_slice();
+        if let Some((&"api", tail)) = rest.split_first() {
+            let rest = tail;
+            if let Some((&"members", tail)) = rest.split_first() {
+                let rest = tail;
+                if let Some((&"me", tail)) = rest.split_first() {
+                    let rest = tail;
+                    if rest.is_empty() {
+                        if method == "GET" {
+                            return Some(Link::GetApiMembersMe);
+                        }
+                        return None;
+                    }
+                }
+                if let Some((&p2, tail)) = rest
+                    .split_first()
+                    .filter(|(segment, _)| !segment.is_empty())
+                {
+                    let rest = tail;
+                    if rest.is_empty() {
+                        if method == "GET" {
+                            return Some(Link::GetApiMembersMemberId {
+                                member_id: decode_path_segment(p2)?,
+                            });
+                        }
+                        if method == "DELETE" {
+                            return Some(Link::DeleteApiMembersMemberId {
+                                member_id: decode_path_segment(p2)?,
+                            });
+                        }
+                        return None;
+                    }
+                    if let Some((&"files", tail)) = rest.split_first() {
+                        let rest = tail;
+                        if !rest.is_empty() {
+                            let wildcard = decode_path_segments(rest)?;
+                            if method == "GET" {
+                                return Some(Link::GetApiMembersMemberIdFilesPath {
+                                    member_id: decode_path_segment(p2)?,
+                                    path: wildcard,
+                                });
+                            }
+                            return None;
+                        }
+                    }
+                }
+            }
+            if let Some((&"teams", tail)) = rest.split_first() {
+                let rest = tail;
+                if let Some((&"", tail)) = rest.split_first() {
+                    let rest = tail;
+                    if rest.is_empty() {
+                        if method == "GET" {
+                            return Some(Link::GetApiTeams);
+                        }
+                        return None;
+                    }
+                }
+            }
+        }
+        None
+    }
+    /// Match a request target such as `/users/42?tab=posts`, ignoring the query and fragment
+    pub fn match_request(method: &str, target: &str) -> Option<Link> {
+        let path = target.split(['?', '#']).next().unwrap_or(target);
+        Self::from_path(method, path)
+    }
+}
+impl std::fmt::Display for Link {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_path(f)
+    }
+}
+/// Static description of a route, available without rescanning sources
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RouteMeta {
+    /// `Link` variant name, e.g. `GetUser`, or `Users::Get` with groups
+    pub name: &'static str,
+    pub method: &'static str,
+    /// Path pattern with `{param}` placeholders
+    pub template: &'static str,
+    pub requires_auth: bool,
+    /// Handler function name
+    pub handler: &'static str,
+    /// Controller module the route is declared in
+    pub controller: &'static str,
+}
+/// Every route of the application, in the order of the `Link` variants
+pub static ROUTES: &[RouteMeta] = &[
+    RouteMeta {
+        name: "GetApiMembersMe",
+        method: "GET",
+        template: "/api/members/me",
+        requires_auth: false,
+        handler: "me",
+        controller: "members",
+    },
+    RouteMeta {
+        name: "GetApiMembersMemberId",
+        method: "GET",
+        template: "/api/members/{member_id}",
+        requires_auth: false,
+        handler: "show",
+        controller: "members",
+    },
+    RouteMeta {
+        name: "DeleteApiMembersMemberId",
+        method: "DELETE",
+        template: "/api/members/{member_id}",
+        requires_auth: false,
+        handler: "remove",
+        controller: "members",
+    },
+    RouteMeta {
+        name: "GetApiMembersMemberIdFilesPath",
+        method: "GET",
+        template: "/api/members/{member_id}/files/{*path}",
+        requires_auth: false,
+        handler: "files",
+        controller: "members",
+    },
+    RouteMeta {
+        name: "GetApiTeams",
+        method: "GET",
+        template: "/api/teams/",
+        requires_auth: false,
+        handler: "teams",
+        controller: "members",
+    },
+];
+/// Write a path segment percent-encoded like JavaScript's `encodeURIComponent`
+fn write_path_segment<W: std::fmt::Write + ?Sized>(
+    w: &mut W,
+    value: &str,
+) -> std::fmt::Result {
+    fn is_unreserved(byte: u8) -> bool {
+        byte.is_ascii_alphanumeric() || b"-_.!~*'()".contains(&byte)
+    }
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    let bytes = value.as_bytes();
+    let mut start = 0;
+    while start < bytes.len() {
+        let end = bytes[start..]
+            .iter()
+            .position(|&byte| !is_unreserved(byte))
+            .map_or(bytes.len(), |offset| start + offset);
+        w.write_str(&value[start..end])?;
+        start = end;
+        while start < bytes.len() && !is_unreserved(bytes[start]) {
+            let byte = bytes[start];
+            w.write_char('%')?;
+            w.write_char(HEX[(byte >> 4) as usize] as char)?;
+            w.write_char(HEX[(byte & 0x0f) as usize] as char)?;
+            start += 1;
+        }
+    }
+    Ok(())
+}
+/// Decode a percent-encoded path segment; `None` if it is malformed or not UTF-8
+fn decode_path_segment(segment: &str) -> Option<String> {
+    let mut decoded = Vec::with_capacity(segment.len());
+    let mut bytes = segment.bytes();
+    while let Some(byte) = bytes.next() {
+        if byte == b'%' {
+            let high = (bytes.next()? as char).to_digit(16)?;
+            let low = (bytes.next()? as char).to_digit(16)?;
+            decoded.push((high * 16 + low) as u8);
+        } else {
+            decoded.push(byte);
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+/// Write each segment of a catch-all value percent-encoded, keeping the `/` separators
+fn write_path_segments<W: std::fmt::Write + ?Sized>(
+    w: &mut W,
+    value: &str,
+) -> std::fmt::Result {
+    for (index, segment) in value.split('/').enumerate() {
+        if index > 0 {
+            w.write_char('/')?;
+        }
+        write_path_segment(w, segment)?;
+    }
+    Ok(())
+}
+/// Decode the segments matched by a catch-all parameter
+fn decode_path_segments(segments: &[&str]) -> Option<String> {
+    let decoded = segments
+        .iter()
+        .map(|segment| decode_path_segment(segment))
+        .collect::<Option<Vec<_>>>()?;
+    Some(decoded.join("/"))
+}
@@ -0,0 +1,57 @@
+use route_info_builder::{Config, RustConfig, generate_links};
+use std::path::Path;
+
+mod common;
+use common::{FIXTURES, assert_fixture};
+
+/// `Link` with `http` integration, refreshed with `UPDATE_FIXTURES=1`
+#[allow(dead_code)]
+mod links {
+    include!("fixtures/http_links.rs");
+}
+
+use links::Link;
+
+#[test]
+fn generated_fixture_is_up_to_date() {
+    let config = Config {
+        controllers_path: Path::new(FIXTURES).to_path_buf(),
+        rust: RustConfig {
+            http: Some(true),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_fixture("http_links.rs", &generate_links(&config).unwrap().rust_code);
+}
+
+#[test]
+fn methods_are_http_methods() {
+    assert_eq!(Link::GetApiPosts.method(), http::Method::GET);
+    assert_eq!(Link::PostApiAuthLogin.method(), http::Method::POST);
+    let link = Link::DeleteApiUsersUserId {
+        user_id: "7".to_string(),
+    };
+    assert_eq!(link.method(), http::Method::DELETE);
+    assert_eq!(link.method().as_str(), link.meta().method);
+}
+
+#[test]
+fn links_convert_to_uris_and_requests() {
+    let link = Link::GetApiUsersUserId {
+        user_id: "a b/ü".to_string(),
+    };
+    let uri = link.to_uri();
+    assert_eq!(uri.path(), "/api/users/a%20b%2F%C3%BC");
+    assert_eq!(uri.query(), None);
+    assert_eq!(http::Uri::from(&link), uri);
+    assert_eq!(http::Uri::from(link.clone()), uri);
+
+    let request = link.request_builder().body(()).unwrap();
+    assert_eq!(request.method(), http::Method::GET);
+    assert_eq!(request.uri(), &uri);
+    assert_eq!(
+        Link::from_path(request.method().as_str(), request.uri().path()),
+        Some(link)
+    );
+}
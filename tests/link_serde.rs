@@ -0,0 +1,101 @@
+use route_info_builder::{Config, RustConfig, generate_links};
+use serde_json::json;
+use std::collections::HashSet;
+use std::path::Path;
+
+mod common;
+use common::{FIXTURES, assert_fixture};
+
+/// `Link` with serde support, refreshed with `UPDATE_FIXTURES=1`
+#[allow(dead_code)]
+mod links {
+    include!("fixtures/serde_links.rs");
+}
+
+use links::{Link, ROUTES};
+
+#[test]
+fn generated_fixture_is_up_to_date() {
+    let config = Config {
+        controllers_path: Path::new(FIXTURES).to_path_buf(),
+        rust: RustConfig {
+            serde: Some(true),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_fixture(
+        "serde_links.rs",
+        &generate_links(&config).unwrap().rust_code,
+    );
+}
+
+#[test]
+fn links_serialize_as_route_and_params() {
+    assert_eq!(
+        serde_json::to_value(Link::GetApiPosts).unwrap(),
+        json!({"route": "GetApiPosts"})
+    );
+    let link = Link::GetApiUsersUserId {
+        user_id: "7".to_string(),
+    };
+    let value = json!({"route": "GetApiUsersUserId", "params": {"user_id": "7"}});
+    assert_eq!(serde_json::to_value(&link).unwrap(), value);
+    assert_eq!(serde_json::from_value::<Link>(value).unwrap(), link);
+}
+
+#[test]
+fn malformed_links_are_rejected() {
+    for value in [
+        json!({"route": "GetApiMissing"}),
+        json!({"route": "GetApiUsersUserId"}),
+        json!({"route": "GetApiUsersUserId", "params": {}}),
+        json!({"params": {"user_id": "7"}}),
+    ] {
+        assert!(
+            serde_json::from_value::<Link>(value.clone()).is_err(),
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn links_are_hashable() {
+    let links: HashSet<Link> = [
+        Link::GetApiPosts,
+        Link::GetApiPosts,
+        Link::DeleteApiUsersUserId {
+            user_id: "7".to_string(),
+        },
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(links.len(), 2);
+}
+
+#[test]
+fn json_schema_describes_every_route() {
+    let schema: serde_json::Value = serde_json::from_str(Link::JSON_SCHEMA).unwrap();
+    assert_eq!(schema["title"], "Link");
+    let variants = schema["oneOf"].as_array().unwrap();
+    assert_eq!(variants.len(), ROUTES.len());
+    for (variant, route) in variants.iter().zip(ROUTES) {
+        assert_eq!(variant["properties"]["route"]["const"], route.name);
+        assert_eq!(
+            variant["description"],
+            format!("{} {}", route.method, route.template)
+        );
+    }
+
+    let user = &variants[5];
+    assert_eq!(user["required"], json!(["route", "params"]));
+    assert_eq!(
+        user["properties"]["params"],
+        json!({
+            "type": "object",
+            "properties": {"user_id": {"type": "string"}},
+            "required": ["user_id"],
+            "additionalProperties": false
+        })
+    );
+}
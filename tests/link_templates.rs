@@ -0,0 +1,147 @@
+use route_info_builder::{Config, RustConfig, TemplateEngine, generate_links};
+use std::path::Path;
+
+mod common;
+use common::{FIXTURES, assert_fixture};
+
+/// `Link` with template helpers for every engine, refreshed with `UPDATE_FIXTURES=1`
+#[allow(dead_code)]
+mod links {
+    include!("fixtures/template_links.rs");
+}
+
+use links::{Link, LinkError};
+
+#[test]
+fn generated_fixture_is_up_to_date() {
+    let config = Config {
+        controllers_path: Path::new(FIXTURES).to_path_buf(),
+        rust: RustConfig {
+            template_engines: Some(vec![
+                TemplateEngine::Tera,
+                TemplateEngine::Minijinja,
+                TemplateEngine::Askama,
+            ]),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_fixture(
+        "template_links.rs",
+        &generate_links(&config).unwrap().rust_code,
+    );
+}
+
+#[test]
+fn from_name_builds_links_from_params() {
+    assert_eq!(
+        Link::from_name("GetApiUsersUserId", [("user_id", 42)]),
+        Ok(Link::GetApiUsersUserId {
+            user_id: "42".to_string()
+        })
+    );
+    assert_eq!(
+        Link::from_name("GetApiPosts", Vec::<(&str, &str)>::new()),
+        Ok(Link::GetApiPosts)
+    );
+}
+
+#[test]
+fn from_name_reports_missing_and_unexpected_params() {
+    let missing = Link::from_name("GetApiUsersUserId", Vec::<(&str, &str)>::new()).unwrap_err();
+    assert_eq!(
+        missing,
+        LinkError::MissingParam {
+            route: "GetApiUsersUserId",
+            param: "user_id",
+        }
+    );
+    assert_eq!(
+        missing.to_string(),
+        "route `GetApiUsersUserId` (/api/users/{user_id}) requires the parameter `user_id`"
+    );
+
+    let unexpected = Link::from_name("GetApiPosts", [("page", 2)]).unwrap_err();
+    assert_eq!(
+        unexpected,
+        LinkError::UnexpectedParam {
+            route: "GetApiPosts",
+            param: "page".to_string(),
+        }
+    );
+    assert_eq!(
+        Link::from_name("GetApiUsersUserId", [("user_id", 1), ("id", 2)]).unwrap_err(),
+        LinkError::UnexpectedParam {
+            route: "GetApiUsersUserId",
+            param: "id".to_string(),
+        }
+    );
+
+    let unknown = Link::from_name("getapiposts", Vec::<(&str, &str)>::new()).unwrap_err();
+    assert_eq!(
+        unknown.to_string(),
+        "unknown route `getapiposts`, did you mean `GetApiPosts`?"
+    );
+}
+
+#[test]
+fn tera_templates_render_links() {
+    let mut tera = tera::Tera::default();
+    links::register_tera_link(&mut tera);
+    tera.add_raw_template(
+        "user",
+        r#"{{ link(name="GetApiUsersUserId", user_id=id) }}"#,
+    )
+    .unwrap();
+
+    let mut context = tera::Context::new();
+    context.insert("id", &42);
+    assert_eq!(tera.render("user", &context).unwrap(), "/api/users/42");
+    context.insert("id", "a b");
+    assert_eq!(tera.render("user", &context).unwrap(), "/api/users/a%20b");
+
+    tera.add_raw_template("missing", r#"{{ link(name="GetApiUsersUserId") }}"#)
+        .unwrap();
+    let error = tera.render("missing", &context).unwrap_err();
+    let message = std::iter::successors(Some(&error as &dyn std::error::Error), |error| {
+        error.source()
+    })
+    .map(ToString::to_string)
+    .collect::<Vec<_>>()
+    .join(": ");
+    assert!(
+        message.contains("requires the parameter `user_id`"),
+        "{message}"
+    );
+}
+
+#[test]
+fn minijinja_templates_render_links() {
+    let mut env = minijinja::Environment::new();
+    links::register_minijinja_link(&mut env);
+
+    let path = env
+        .render_str(
+            "{{ link(name='GetApiUsersUserId', user_id=id) }}",
+            minijinja::context! { id => 42 },
+        )
+        .unwrap();
+    assert_eq!(path, "/api/users/42");
+
+    let error = env
+        .render_str("{{ link(name='GetApiPosts', page=2) }}", ())
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("has no parameter `page`"),
+        "{error}"
+    );
+}
+
+#[test]
+fn askama_helper_returns_paths() {
+    assert_eq!(
+        links::link("DeleteApiUsersUserId", &[("user_id", "ü")]).unwrap(),
+        "/api/users/%C3%BC"
+    );
+    assert!(links::link("DeleteApiUsersUserId", &[("id", "7")]).is_err());
+}
@@ -0,0 +1,117 @@
+use route_info_builder::{Config, RustConfig, generate_links};
+use std::path::Path;
+
+mod common;
+use common::{FIXTURES, TempDir, assert_fixture};
+
+/// Query types the generated `Link` refers to
+mod controllers {
+    pub mod users {
+        #[derive(Debug, Clone, PartialEq, serde::Serialize)]
+        pub struct ListQuery {
+            pub page: Option<u32>,
+            pub search: Option<String>,
+        }
+    }
+}
+
+/// `Link` with query fields and a base URL, refreshed with `UPDATE_FIXTURES=1`
+#[allow(dead_code)]
+mod links {
+    include!("fixtures/url_links.rs");
+}
+
+use controllers::users::ListQuery;
+use links::Link;
+
+fn config(controllers_path: &Path) -> Config {
+    Config {
+        controllers_path: controllers_path.to_path_buf(),
+        rust: RustConfig {
+            query_fields: Some(true),
+            base_url: Some("https://example.com/".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn generated_fixture_is_up_to_date() {
+    assert_fixture(
+        "url_links.rs",
+        &generate_links(&config(Path::new(FIXTURES)))
+            .unwrap()
+            .rust_code,
+    );
+}
+
+#[test]
+fn query_fields_are_serialized_into_urls() {
+    let link = Link::GetApiUsers {
+        query: Some(ListQuery {
+            page: Some(2),
+            search: Some("a b&c".to_string()),
+        }),
+    };
+    assert_eq!(link.to_path(), "/api/users/");
+    assert_eq!(
+        link.to_path_and_query(),
+        "/api/users/?page=2&search=a+b%26c"
+    );
+    assert_eq!(
+        link.to_url(),
+        "https://example.com/api/users/?page=2&search=a+b%26c"
+    );
+    assert_eq!(
+        link.to_url_with_base("http://localhost:3000/"),
+        "http://localhost:3000/api/users/?page=2&search=a+b%26c"
+    );
+}
+
+#[test]
+fn empty_queries_are_left_out() {
+    assert_eq!(
+        Link::GetApiUsers { query: None }.to_url(),
+        "https://example.com/api/users/"
+    );
+    let empty = ListQuery {
+        page: None,
+        search: None,
+    };
+    assert_eq!(
+        Link::GetApiUsers { query: Some(empty) }.to_path_and_query(),
+        "/api/users/"
+    );
+    assert_eq!(Link::BASE_URL, "https://example.com/");
+    assert_eq!(
+        Link::GetApiUsersUserId {
+            user_id: "a b".to_string()
+        }
+        .to_url(),
+        "https://example.com/api/users/a%20b"
+    );
+}
+
+#[test]
+fn path_parameters_may_not_take_generated_names() {
+    for param in ["query", "body", "token", "w"] {
+        let source = format!(
+            r#"
+pub async fn show(Path({param}): Path<String>) -> Result<Response> {{
+    format::empty()
+}}
+
+pub fn routes() -> Routes {{
+    Routes::new().prefix("api").add("/items/{{{param}}}", get(show))
+}}
+"#
+        );
+        let dir = TempDir::with_controllers("reserved", &[("items.rs", &source)]);
+        let error = generate_links(&config(dir.path())).unwrap_err().to_string();
+        assert!(
+            error.contains(&format!("'{param}' of GET /api/items/{{{param}}}")),
+            "{error}"
+        );
+    }
+}
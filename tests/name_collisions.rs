@@ -0,0 +1,104 @@
+use route_info_builder::{
+    CollisionStrategy, Config, NamingConfig, generate_links, generate_ts_client,
+    scan_controllers_folder,
+};
+
+mod common;
+use common::TempDir;
+
+/// `GET /api/users/me` and `POST /api/users-me` in different controllers, which
+/// share the name `api_users_me` once methods are left out of names
+fn colliding_controllers(name: &str) -> TempDir {
+    TempDir::with_controllers(
+        name,
+        &[
+            (
+                "users.rs",
+                r#"
+pub async fn me() -> Result<Response> {
+    format::empty()
+}
+
+pub fn routes() -> Routes {
+    Routes::new().prefix("api/users").add("/me", get(me))
+}
+"#,
+            ),
+            (
+                "profiles.rs",
+                r#"
+pub async fn update() -> Result<Response> {
+    format::empty()
+}
+
+pub fn routes() -> Routes {
+    Routes::new().prefix("api").add("/users-me", post(update))
+}
+"#,
+            ),
+        ],
+    )
+}
+
+fn names_with(name: &str, strategy: Option<CollisionStrategy>) -> Result<Vec<String>, String> {
+    let dir = colliding_controllers(name);
+    let config = Config {
+        controllers_path: dir.path().to_path_buf(),
+        naming: NamingConfig {
+            include_method_in_names: Some(false),
+            collision_strategy: strategy,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let routes = scan_controllers_folder(&config).map_err(|e| e.to_string())?;
+    Ok(routes.into_iter().map(|route| route.name).collect())
+}
+
+#[test]
+fn strategies_make_names_unique() {
+    assert_eq!(
+        names_with("number", Some(CollisionStrategy::Number)).unwrap(),
+        ["api_users_me", "api_users_me_2"]
+    );
+    assert_eq!(
+        names_with("method", Some(CollisionStrategy::Method)).unwrap(),
+        ["api_users_me_post", "api_users_me_get"]
+    );
+    assert_eq!(
+        names_with("controller", Some(CollisionStrategy::Controller)).unwrap(),
+        ["profiles_api_users_me", "users_api_users_me"]
+    );
+    assert_eq!(
+        names_with("default", None).unwrap(),
+        names_with("numbered", Some(CollisionStrategy::Number)).unwrap()
+    );
+}
+
+#[test]
+fn error_strategy_names_both_routes() {
+    let error = names_with("error", Some(CollisionStrategy::Error)).unwrap_err();
+    assert!(error.contains("POST /api/users-me"), "{error}");
+    assert!(error.contains("GET /api/users/me"), "{error}");
+}
+
+#[test]
+fn rust_and_typescript_keep_every_route() {
+    let dir = colliding_controllers("generators");
+    let config = Config {
+        controllers_path: dir.path().to_path_buf(),
+        naming: NamingConfig {
+            include_method_in_names: Some(false),
+            collision_strategy: Some(CollisionStrategy::Method),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let rust = generate_links(&config).unwrap().rust_code;
+    let typescript = generate_ts_client(&config).unwrap();
+
+    assert!(rust.contains("ApiUsersMeGet"));
+    assert!(rust.contains("ApiUsersMePost"));
+    assert!(typescript.contains("apiUsersMeGet"));
+    assert!(typescript.contains("apiUsersMePost"));
+}
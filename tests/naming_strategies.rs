@@ -0,0 +1,129 @@
+use route_info_builder::{Config, NamingConfig, NamingStrategy, scan_controllers_folder};
+use std::path::Path;
+
+mod common;
+use common::{FIXTURES, TempDir};
+
+/// `(method, path, name)` of every fixture route with the given naming config
+fn route_names(naming: NamingConfig) -> Vec<(String, String, String)> {
+    let config = Config {
+        controllers_path: Path::new(FIXTURES).to_path_buf(),
+        naming,
+        ..Default::default()
+    };
+    scan_controllers_folder(&config)
+        .unwrap()
+        .into_iter()
+        .map(|route| (route.method, route.path, route.name))
+        .collect()
+}
+
+fn name_of(names: &[(String, String, String)], method: &str, path: &str) -> String {
+    names
+        .iter()
+        .find(|(m, p, _)| m == method && p == path)
+        .map(|(_, _, name)| name.clone())
+        .unwrap_or_else(|| panic!("no route {method} {path}"))
+}
+
+#[test]
+fn rest_strategy_names_resources() {
+    let names = route_names(NamingConfig {
+        strategy: Some(NamingStrategy::Rest),
+        ..Default::default()
+    });
+
+    assert_eq!(name_of(&names, "GET", "/api/users/"), "list_users");
+    assert_eq!(name_of(&names, "POST", "/api/users/"), "create_user");
+    assert_eq!(
+        name_of(&names, "GET", "/api/users/{user_id}"),
+        "get_user_by_id"
+    );
+    assert_eq!(
+        name_of(&names, "DELETE", "/api/users/{user_id}"),
+        "delete_user_by_id"
+    );
+    assert_eq!(
+        name_of(&names, "POST", "/api/posts/{post_id}/comments"),
+        "create_post_comment"
+    );
+    // Actions are not resources and keep the path-based name
+    assert_eq!(
+        name_of(&names, "POST", "/api/auth/login"),
+        "post_api_auth_login"
+    );
+}
+
+#[test]
+fn path_strategy_is_the_default() {
+    let names = route_names(NamingConfig::default());
+    assert_eq!(
+        name_of(&names, "GET", "/api/users/{user_id}"),
+        "get_api_users_user_id"
+    );
+    assert_eq!(
+        names,
+        route_names(NamingConfig {
+            strategy: Some(NamingStrategy::Path),
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn handler_strategy_uses_handler_names() {
+    let names = route_names(NamingConfig {
+        strategy: Some(NamingStrategy::Handler),
+        ..Default::default()
+    });
+    assert_eq!(name_of(&names, "POST", "/api/auth/login"), "login");
+    assert_eq!(name_of(&names, "GET", "/api/users/{user_id}"), "get_one");
+    // Both controllers have a `list` handler
+    assert_eq!(name_of(&names, "GET", "/api/users/"), "get_api_users");
+    assert_eq!(name_of(&names, "GET", "/api/posts/"), "get_api_posts");
+
+    let names = route_names(NamingConfig {
+        strategy: Some(NamingStrategy::Handler),
+        prefix_controller: Some(true),
+        ..Default::default()
+    });
+    assert_eq!(name_of(&names, "GET", "/api/users/"), "users_list");
+    assert_eq!(name_of(&names, "GET", "/api/posts/"), "posts_list");
+}
+
+#[test]
+fn handler_strategy_names_closures_by_path() {
+    let dir = TempDir::with_controllers(
+        "closures",
+        &[(
+            "health.rs",
+            r#"
+pub async fn ready() -> Result<Response> {
+    format::empty()
+}
+
+pub fn routes() -> Routes {
+    Routes::new()
+        .prefix("health")
+        .add("/live", get(|| async { format::empty() }))
+        .add("/ready", get(ready))
+}
+"#,
+        )],
+    );
+
+    let config = Config {
+        controllers_path: dir.path().to_path_buf(),
+        naming: NamingConfig {
+            strategy: Some(NamingStrategy::Handler),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let names: Vec<String> = scan_controllers_folder(&config)
+        .unwrap()
+        .into_iter()
+        .map(|route| route.name)
+        .collect();
+    assert_eq!(names, ["get_health_live", "ready"]);
+}
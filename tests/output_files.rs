@@ -0,0 +1,52 @@
+use route_info_builder::{WriteStatus, write_if_changed};
+use std::fs::{self, File};
+use std::time::{Duration, SystemTime};
+
+mod common;
+use common::TempDir;
+
+#[test]
+fn unchanged_content_keeps_the_file_untouched() {
+    let dir = TempDir::new("output-unchanged");
+    let path = dir.join("generated/client.ts");
+
+    assert_eq!(
+        write_if_changed(&path, "export {};\n").unwrap(),
+        WriteStatus::Created
+    );
+    // Backdate the file so any rewrite would be visible in its mtime
+    let backdated = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+    File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(backdated)
+        .unwrap();
+
+    assert_eq!(
+        write_if_changed(&path, "export {};\n").unwrap(),
+        WriteStatus::Unchanged
+    );
+    assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), backdated);
+
+    assert_eq!(
+        write_if_changed(&path, "export const a = 1;\n").unwrap(),
+        WriteStatus::Updated
+    );
+    assert_ne!(fs::metadata(&path).unwrap().modified().unwrap(), backdated);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "export const a = 1;\n");
+}
+
+#[test]
+fn temporary_files_are_cleaned_up() {
+    let dir = TempDir::new("output-temp");
+    let path = dir.join("links.rs");
+    write_if_changed(&path, "// first\n").unwrap();
+    write_if_changed(&path, "// second\n").unwrap();
+
+    let entries: Vec<_> = fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(entries, ["links.rs"]);
+}
@@ -0,0 +1,195 @@
+use route_info_builder::{Config, generate_links};
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Instant;
+
+mod common;
+use common::{FIXTURES, assert_fixture};
+
+/// `Link` generated from the controller fixtures, refreshed with `UPDATE_FIXTURES=1`
+#[allow(dead_code)]
+mod links {
+    include!("fixtures/links.rs");
+}
+
+use links::{Link, ROUTES, RouteMeta};
+
+fn sample_links() -> Vec<Link> {
+    vec![
+        Link::PostApiAuthLogin,
+        Link::GetApiPosts,
+        Link::PostApiPostsPostIdComments {
+            post_id: "42".to_string(),
+        },
+        Link::GetApiUsersUserId {
+            user_id: "a b/ü".to_string(),
+        },
+        Link::DeleteApiUsersUserId {
+            user_id: "7".to_string(),
+        },
+    ]
+}
+
+#[test]
+fn generated_fixture_is_up_to_date() {
+    let config = Config {
+        controllers_path: Path::new(FIXTURES).to_path_buf(),
+        ..Default::default()
+    };
+    assert_fixture("links.rs", &generate_links(&config).unwrap().rust_code);
+}
+
+#[test]
+fn static_paths_are_borrowed() {
+    assert!(matches!(
+        Link::GetApiPosts.to_path(),
+        Cow::Borrowed("/api/posts/")
+    ));
+    assert!(matches!(
+        Link::GetApiUsersUserId {
+            user_id: "1".to_string()
+        }
+        .to_path(),
+        Cow::Owned(_)
+    ));
+}
+
+#[test]
+fn write_path_and_display_match_to_path() {
+    let mut buffer = String::new();
+    for link in sample_links() {
+        buffer.clear();
+        link.write_path(&mut buffer).unwrap();
+        assert_eq!(buffer, link.to_path());
+        assert_eq!(link.to_string(), link.to_path());
+    }
+    assert_eq!(sample_links()[3].to_path(), "/api/users/a%20b%2F%C3%BC");
+}
+
+#[test]
+fn routes_table_lists_every_variant_in_order() {
+    let routes: Vec<(&str, &str, &str)> = ROUTES
+        .iter()
+        .map(|route| (route.method, route.template, route.name))
+        .collect();
+    assert_eq!(
+        routes,
+        [
+            ("POST", "/api/auth/login", "PostApiAuthLogin"),
+            ("POST", "/api/auth/logout", "PostApiAuthLogout"),
+            ("GET", "/api/posts/", "GetApiPosts"),
+            (
+                "POST",
+                "/api/posts/{post_id}/comments",
+                "PostApiPostsPostIdComments"
+            ),
+            ("GET", "/api/users/", "GetApiUsers"),
+            ("GET", "/api/users/{user_id}", "GetApiUsersUserId"),
+            ("POST", "/api/users/", "PostApiUsers"),
+            ("DELETE", "/api/users/{user_id}", "DeleteApiUsersUserId"),
+        ]
+    );
+    assert_eq!(ROUTES.iter().filter(|route| route.requires_auth).count(), 4);
+}
+
+#[test]
+fn accessors_read_the_routes_table() {
+    let link = Link::DeleteApiUsersUserId {
+        user_id: "7".to_string(),
+    };
+    assert_eq!(
+        *link.meta(),
+        RouteMeta {
+            name: "DeleteApiUsersUserId",
+            method: "DELETE",
+            template: "/api/users/{user_id}",
+            requires_auth: true,
+            handler: "remove",
+            controller: "users",
+        }
+    );
+    for link in sample_links() {
+        let meta = link.meta();
+        assert!(ROUTES.iter().any(|route| std::ptr::eq(route, meta)));
+        assert_eq!(link.name(), meta.name);
+        assert_eq!(link.method(), meta.method);
+        assert_eq!(link.template(), meta.template);
+        assert_eq!(link.requires_auth(), meta.requires_auth);
+        assert_eq!(link.handler(), meta.handler);
+        assert_eq!(link.controller(), meta.controller);
+    }
+}
+
+/// `encodeURIComponent` of every printable ASCII character followed by `ü€😀`, from Node
+const ENCODE_URI_COMPONENT: &str = "%20!%22%23%24%25%26'()*%2B%2C-.%2F0123456789%3A%3B%3C%3D%3E%3F%40ABCDEFGHIJKLMNOPQRSTUVWXYZ%5B%5C%5D%5E_%60abcdefghijklmnopqrstuvwxyz%7B%7C%7D~%C3%BC%E2%82%AC%F0%9F%98%80";
+
+#[test]
+fn parameters_are_encoded_like_encode_uri_component() {
+    let link = Link::GetApiUsersUserId {
+        user_id: (' '..='~').chain("ü€😀".chars()).collect(),
+    };
+    assert_eq!(link.to_path(), format!("/api/users/{ENCODE_URI_COMPONENT}"));
+}
+
+#[test]
+fn encoded_parameters_round_trip_through_from_path() {
+    for user_id in ["a/b", "/", "100%", "%2F", "a b", " ", "ü€😀", "?#&="] {
+        let link = Link::GetApiUsersUserId {
+            user_id: user_id.to_string(),
+        };
+        let path = link.to_path();
+        assert_eq!(path.matches('/').count(), 3, "{path}");
+        assert_eq!(Link::from_path("GET", &path), Some(link));
+    }
+}
+
+/// Compare writing into a reused buffer with allocating a path per link:
+/// `cargo test --release --test path_building -- --ignored --nocapture`
+#[test]
+#[ignore]
+fn bench_path_building() {
+    const ROUNDS: usize = 200_000;
+    let links = sample_links();
+
+    let start = Instant::now();
+    let mut total = 0;
+    for _ in 0..ROUNDS {
+        for link in &links {
+            total += std::hint::black_box(link.to_path().into_owned()).len();
+        }
+    }
+    let allocating = start.elapsed();
+
+    let start = Instant::now();
+    let mut buffer = String::with_capacity(64);
+    let mut written = 0;
+    for _ in 0..ROUNDS {
+        for link in &links {
+            buffer.clear();
+            link.write_path(&mut buffer).unwrap();
+            written += std::hint::black_box(&buffer).len();
+        }
+    }
+    let writing = start.elapsed();
+
+    let start = Instant::now();
+    let mut page = String::new();
+    for _ in 0..ROUNDS / 100 {
+        page.clear();
+        for link in &links {
+            write!(page, "<a href=\"{link}\"></a>").unwrap();
+        }
+    }
+    let rendering = start.elapsed();
+
+    assert_eq!(total, written);
+    println!(
+        "{} paths: to_path {:?}, write_path {:?}; {} links rendered with Display in {:?}",
+        ROUNDS * links.len(),
+        allocating,
+        writing,
+        ROUNDS / 100 * links.len(),
+        rendering
+    );
+}
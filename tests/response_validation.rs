@@ -0,0 +1,68 @@
+use route_info_builder::{Config, TypeScriptConfig, generate_ts_client, generate_zod_schemas};
+use std::path::Path;
+
+mod common;
+use common::FIXTURES;
+
+fn config(typescript: TypeScriptConfig) -> Config {
+    Config {
+        controllers_path: Path::new(FIXTURES).to_path_buf(),
+        typescript,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn array_responses_use_the_array_schema() {
+    let config = config(TypeScriptConfig {
+        generate_zod_schemas: Some(true),
+        validate_responses: Some(true),
+        ..Default::default()
+    });
+    let client = generate_ts_client(&config).unwrap();
+    let schemas = generate_zod_schemas(&config).unwrap();
+
+    // `list` returns `Json<Vec<UserResponse>>`, `get_one` a single `UserResponse`
+    let list = &client[client.find("getApiUsers: async (").unwrap()..];
+    let list = &list[..list.find("getApiUsersUserId: async (").unwrap()];
+    assert!(
+        list.contains("responseSchema: UserResponseArraySchema,"),
+        "{list}"
+    );
+    let get_one = &client[client.find("getApiUsersUserId: async (").unwrap()..];
+    assert!(get_one.contains("responseSchema: UserResponseSchema,"));
+
+    assert!(client.contains("  UserResponseArraySchema,\n"));
+    assert!(client.contains("} from \"./schemas\";"));
+    assert!(schemas.contains("export const UserResponseArraySchema = z.array("));
+}
+
+#[test]
+fn validation_defaults_follow_the_config() {
+    let client = generate_ts_client(&config(TypeScriptConfig {
+        generate_zod_schemas: Some(true),
+        ..Default::default()
+    }))
+    .unwrap();
+    assert!(client.contains("  validateRequests: false,\n  validateResponses: false,\n"));
+
+    let client = generate_ts_client(&config(TypeScriptConfig {
+        generate_zod_schemas: Some(true),
+        validate_requests: Some(true),
+        validate_responses: Some(true),
+        ..Default::default()
+    }))
+    .unwrap();
+    assert!(client.contains("  validateRequests: true,\n  validateResponses: true,\n"));
+}
+
+#[test]
+fn schemas_are_only_referenced_when_generated() {
+    let client = generate_ts_client(&config(TypeScriptConfig {
+        validate_responses: Some(true),
+        ..Default::default()
+    }))
+    .unwrap();
+    assert!(!client.contains("responseSchema: UserResponse"));
+    assert!(!client.contains("./schemas"));
+}
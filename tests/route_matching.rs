@@ -0,0 +1,104 @@
+use route_info_builder::{Config, generate_links};
+use std::path::Path;
+
+mod common;
+use common::{FIXTURES, assert_fixture};
+
+/// `Link` generated from the matcher fixtures, refreshed with `UPDATE_FIXTURES=1`
+#[allow(dead_code)]
+mod links {
+    include!("fixtures/matcher_links.rs");
+}
+
+use links::Link;
+
+#[test]
+fn generated_fixture_is_up_to_date() {
+    let config = Config {
+        controllers_path: Path::new(FIXTURES).with_file_name("matcher_controllers"),
+        ..Default::default()
+    };
+    assert_fixture(
+        "matcher_links.rs",
+        &generate_links(&config).unwrap().rust_code,
+    );
+}
+
+#[test]
+fn static_segments_beat_parameters() {
+    assert_eq!(
+        Link::from_path("GET", "/api/members/me"),
+        Some(Link::GetApiMembersMe)
+    );
+    assert_eq!(
+        Link::from_path("GET", "/api/members/mel"),
+        Some(Link::GetApiMembersMemberId {
+            member_id: "mel".to_string()
+        })
+    );
+}
+
+#[test]
+fn wildcards_capture_the_rest_of_the_path() {
+    assert_eq!(
+        Link::from_path("GET", "/api/members/7/files/docs/a%20b.txt"),
+        Some(Link::GetApiMembersMemberIdFilesPath {
+            member_id: "7".to_string(),
+            path: "docs/a b.txt".to_string(),
+        })
+    );
+    assert_eq!(Link::from_path("GET", "/api/members/7/files"), None);
+}
+
+#[test]
+fn wildcards_round_trip_through_from_path() {
+    let link = Link::GetApiMembersMemberIdFilesPath {
+        member_id: "a/b c".to_string(),
+        path: "100% ü/€ x/?".to_string(),
+    };
+    let path = link.to_path();
+    assert_eq!(
+        path,
+        "/api/members/a%2Fb%20c/files/100%25%20%C3%BC/%E2%82%AC%20x/%3F"
+    );
+    assert_eq!(Link::from_path("GET", &path), Some(link));
+}
+
+#[test]
+fn trailing_slashes_must_match_the_template() {
+    assert_eq!(
+        Link::from_path("GET", "/api/teams/"),
+        Some(Link::GetApiTeams)
+    );
+    assert_eq!(Link::from_path("GET", "/api/teams"), None);
+    assert_eq!(Link::from_path("GET", "/api/members/7/"), None);
+    // An empty segment is not a parameter value
+    assert_eq!(Link::from_path("GET", "/api/members/"), None);
+}
+
+#[test]
+fn invalid_percent_encoding_does_not_match() {
+    assert_eq!(Link::from_path("GET", "/api/members/%zz"), None);
+    assert_eq!(Link::from_path("GET", "/api/members/%2"), None);
+    // Valid escapes of bytes that are not UTF-8
+    assert_eq!(Link::from_path("GET", "/api/members/%FF"), None);
+}
+
+#[test]
+fn method_mismatch_does_not_match() {
+    assert_eq!(
+        Link::from_path("DELETE", "/api/members/7"),
+        Some(Link::DeleteApiMembersMemberId {
+            member_id: "7".to_string()
+        })
+    );
+    assert_eq!(Link::from_path("POST", "/api/members/7"), None);
+    // Like a 405, the static match wins even though the parameter route allows DELETE
+    assert_eq!(Link::from_path("DELETE", "/api/members/me"), None);
+    assert_eq!(
+        Link::match_request("GET", "/api/members/7?tab=files#top"),
+        Some(Link::GetApiMembersMemberId {
+            member_id: "7".to_string()
+        })
+    );
+}
@@ -0,0 +1,173 @@
+use route_info_builder::{Config, RustClientConfig, RustConfig, generate_rust_client};
+use std::path::Path;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+mod common;
+use common::{FIXTURES, assert_fixture};
+
+/// Request and response types the generated client refers to
+mod dto {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Serialize)]
+    pub struct CommentParams {
+        pub body: String,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct CreateUserParams {
+        pub display_name: String,
+        pub email: String,
+    }
+
+    #[derive(Debug, Serialize)]
+    pub struct ListQuery {
+        pub page: Option<u32>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    pub struct PostResponse {
+        pub id: i32,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    pub struct UserResponse {
+        pub id: i32,
+        pub name: String,
+    }
+}
+
+/// Client generated from the controller fixtures, refreshed with `UPDATE_FIXTURES=1`
+#[allow(dead_code)]
+mod client {
+    include!("fixtures/client.rs");
+}
+
+use client::{ApiClient, ClientError};
+use dto::{CreateUserParams, ListQuery, UserResponse};
+
+#[test]
+fn generated_fixture_is_up_to_date() {
+    let config = Config {
+        controllers_path: Path::new(FIXTURES).to_path_buf(),
+        rust: RustConfig {
+            client: RustClientConfig {
+                types_module: Some("crate::dto".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_fixture("client.rs", &generate_rust_client(&config).unwrap());
+}
+
+/// Answer one request with `status` and a JSON `body`; resolves to the raw request
+async fn serve_once(
+    status: &'static str,
+    body: &'static str,
+) -> (String, tokio::task::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0; 4096];
+        loop {
+            let read = socket.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+            let text = String::from_utf8_lossy(&request).to_lowercase();
+            if let Some(end) = text.find("\r\n\r\n") {
+                let length = text
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length: "))
+                    .map_or(0, |length| length.trim().parse().unwrap());
+                if request.len() >= end + 4 + length {
+                    break;
+                }
+            }
+        }
+        let response = format!(
+            "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+        String::from_utf8(request).unwrap()
+    });
+    (base_url, server)
+}
+
+#[tokio::test]
+async fn queries_are_sent_and_responses_decoded() {
+    let (base_url, server) = serve_once("200 OK", r#"[{"id": 1, "name": "Ann"}]"#).await;
+    let users = ApiClient::new(format!("{base_url}/"))
+        .with_token("secret")
+        .get_api_users(Some(&ListQuery { page: Some(2) }))
+        .await
+        .unwrap();
+    assert_eq!(
+        users,
+        [UserResponse {
+            id: 1,
+            name: "Ann".to_string()
+        }]
+    );
+
+    let request = server.await.unwrap();
+    assert!(
+        request.starts_with("GET /api/users/?page=2 HTTP/1.1\r\n"),
+        "{request}"
+    );
+    // The token is only sent to routes that require authentication
+    assert!(!request.to_lowercase().contains("authorization"));
+}
+
+#[tokio::test]
+async fn authenticated_routes_send_the_token_and_body() {
+    let (base_url, server) = serve_once("201 Created", r#"{"id": 2, "name": "Bo"}"#).await;
+    let user = ApiClient::new(base_url)
+        .with_token("secret")
+        .post_api_users(&CreateUserParams {
+            display_name: "Bo".to_string(),
+            email: "bo@example.com".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(user.id, 2);
+
+    let request = server.await.unwrap();
+    assert!(
+        request.starts_with("POST /api/users/ HTTP/1.1\r\n"),
+        "{request}"
+    );
+    assert!(
+        request
+            .to_lowercase()
+            .contains("authorization: bearer secret\r\n")
+    );
+    assert!(request.ends_with(r#"{"displayName":"Bo","email":"bo@example.com"}"#));
+}
+
+#[tokio::test]
+async fn error_responses_keep_the_server_error() {
+    let (base_url, server) = serve_once(
+        "400 Bad Request",
+        r#"{"error": "Bad Request", "description": "{\"code\": \"invalid_id\", \"message\": \"not a user\"}"}"#,
+    )
+    .await;
+    let error = ApiClient::new(base_url)
+        .get_api_users_user_id(-1)
+        .await
+        .unwrap_err();
+    server.await.unwrap();
+
+    let ClientError::Api { status, error } = error else {
+        panic!("expected an API error, got {error:?}");
+    };
+    assert_eq!(status, reqwest::StatusCode::BAD_REQUEST);
+    let details = error.unwrap().details().unwrap();
+    assert_eq!(details.code, "invalid_id");
+    assert_eq!(details.message, "not a user");
+}
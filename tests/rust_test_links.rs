@@ -0,0 +1,115 @@
+use route_info_builder::{Config, RustConfig, RustTestHelpersConfig, generate_rust_test_links};
+use std::path::Path;
+
+mod common;
+use common::FIXTURES;
+
+/// Generated helpers, checked to parse; compiling them needs `axum-test`
+fn generate(rust: RustConfig) -> String {
+    let config = Config {
+        controllers_path: Path::new(FIXTURES).to_path_buf(),
+        rust,
+        ..Default::default()
+    };
+    let code = generate_rust_test_links(&config).unwrap();
+    syn::parse_file(&code).unwrap();
+    code
+}
+
+/// The implementation of the helper starting with `signature`, up to the next function
+fn implementation<'a>(code: &'a str, signature: &str) -> &'a str {
+    let code = &code[code.find("impl TestLinks for").unwrap()..];
+    let start = code
+        .find(signature)
+        .unwrap_or_else(|| panic!("missing {signature} in\n{code}"));
+    let rest = &code[start + signature.len()..];
+    let end = rest.find(" fn ").unwrap_or(rest.len());
+    &code[start..start + signature.len() + end]
+}
+
+#[test]
+fn every_route_gets_a_request_and_a_call_helper() {
+    let code = generate(RustConfig::default());
+    assert!(code.contains("pub trait TestLinks {"));
+    assert!(code.contains("impl TestLinks for axum_test::TestServer {"));
+    for route in [
+        "post_api_auth_login",
+        "post_api_auth_logout",
+        "get_api_posts",
+        "post_api_posts_post_id_comments",
+        "get_api_users",
+        "get_api_users_user_id",
+        "post_api_users",
+        "delete_api_users_user_id",
+    ] {
+        assert!(code.contains(&format!("fn {route}_request(")), "{route}");
+        assert!(code.contains(&format!("async fn {route}(")), "{route}");
+    }
+}
+
+#[test]
+fn arguments_follow_the_handler_extractors() {
+    let code = generate(RustConfig::default());
+
+    // Typed path parameters, a JSON body and a bearer token for authenticated routes
+    let create = implementation(
+        &code,
+        "fn post_api_posts_post_id_comments_request(\n        &self,",
+    );
+    assert!(create.contains("post_id: i32,"), "{create}");
+    assert!(create.contains("body: &crate::controllers::posts::CommentParams,"));
+    assert!(create.contains("token: &str,"));
+    assert!(create.contains("post_id: post_id.to_string(),"));
+    assert!(create.contains(".json(body)"));
+    assert!(create.contains(".authorization_bearer(token)"));
+
+    // Public routes take no token and queries are optional
+    let list = implementation(&code, "fn get_api_users_request(\n        &self,");
+    assert!(list.contains("query: Option<&crate::controllers::users::ListQuery>,"));
+    assert!(list.contains("Some(query) => request.add_query_params(query),"));
+    assert!(!list.contains("token"));
+
+    // Call helpers decode the handler's JSON response
+    let get = implementation(&code, "async fn get_api_users_user_id(");
+    assert!(
+        get.contains(") -> crate::controllers::users::UserResponse {"),
+        "{get}"
+    );
+    assert!(get.contains("response.assert_status_success();"));
+    assert!(get.contains("response.json()"));
+    let delete = implementation(&code, "async fn delete_api_users_user_id(");
+    assert!(
+        delete.contains(") -> axum_test::TestResponse {"),
+        "{delete}"
+    );
+}
+
+#[test]
+fn paths_and_methods_come_from_link() {
+    let code = generate(RustConfig::default());
+    assert!(
+        code.contains(
+            "fn link_request(&self, link: &crate::links::Link) -> axum_test::TestRequest {"
+        )
+    );
+    assert!(code.contains("axum::http::Method::from_bytes(link.method().as_bytes())"));
+    assert!(code.contains("&link.to_path(),"));
+
+    let code = generate(RustConfig {
+        http: Some(true),
+        query_fields: Some(true),
+        test_helpers: RustTestHelpersConfig {
+            links_module: Some("my_app::links".to_string()),
+            types_module: Some("my_app::views".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert!(code.contains("fn link_request(&self, link: &my_app::links::Link)"));
+    assert!(code.contains("self.method(link.method(), &link.to_path())"));
+    assert!(code.contains(
+        "&my_app::links::Link::GetApiUsers {\n                    query: None,\n                },"
+    ));
+    assert!(code.contains("body: &my_app::views::CreateUserParams,"));
+    assert!(!code.contains("crate::"));
+}
@@ -0,0 +1,121 @@
+use route_info_builder::{Config, TypeScriptConfig, generate_zod_schemas};
+
+mod common;
+use common::TempDir;
+
+const SIGNUP_CONTROLLER: &str = r#"
+use loco_rs::prelude::*;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SignupParams {
+    #[validate(length(min = 3, max = 32), does_not_contain(pattern = "a\"b\\c"))]
+    pub username: String,
+    #[validate(email(message = "Use a \"real\" address"))]
+    pub email: String,
+    #[validate(url)]
+    pub website: Option<String>,
+    #[validate(range(min = 13, exclusive_max = 150))]
+    pub age: u32,
+    #[validate(contains = "@")]
+    pub handle: String,
+    #[validate(length(min = 8))]
+    pub password: String,
+    #[validate(must_match(other = "password"))]
+    pub password_confirmation: String,
+    #[validate(required)]
+    pub terms: Option<bool>,
+    #[validate(non_control_character)]
+    pub bio: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Category {
+    pub name: String,
+    pub children: Vec<Category>,
+    pub owner: Owner,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Owner {
+    pub id: i32,
+}
+
+pub async fn signup(Json(params): Json<SignupParams>) -> Result<Response> {
+    format::json(Owner { id: 1 })
+}
+
+pub async fn categories() -> Result<Response> {
+    format::json(Vec::<Category>::new())
+}
+
+pub fn routes() -> Routes {
+    Routes::new()
+        .prefix("api")
+        .add("/signup", post(signup))
+        .add("/categories", get(categories))
+}
+"#;
+
+fn generate(generate_types: bool) -> String {
+    let dir = TempDir::with_controllers("zod", &[("signup.rs", SIGNUP_CONTROLLER)]);
+    let config = Config {
+        controllers_path: dir.path().to_path_buf(),
+        typescript: TypeScriptConfig {
+            generate_types: Some(generate_types),
+            generate_zod_schemas: Some(true),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    generate_zod_schemas(&config).unwrap()
+}
+
+#[test]
+fn validator_rules_become_zod_checks() {
+    let schemas = generate(true);
+    for check in [
+        r#""username": z.string().min(3).max(32).refine("#,
+        r#""email": z.string().email({ message: "Use a \"real\" address" }),"#,
+        r#""website": z.string().url().nullable().optional(),"#,
+        r#""age": z.number().gte(13).lt(150),"#,
+        r#""handle": z.string().includes("@"),"#,
+        r#""password": z.string().min(8),"#,
+        // `required` makes an `Option` mandatory
+        r#""terms": z.boolean(),"#,
+        r#""bio": z.string().regex(/^[^\p{Cc}]*$/u),"#,
+        r#".refine((data) => data["password_confirmation"] === data["password"], {"#,
+        r#"message: "must match password","#,
+    ] {
+        assert!(schemas.contains(check), "missing {check} in\n{schemas}");
+    }
+}
+
+#[test]
+fn patterns_and_messages_are_escaped() {
+    let schemas = generate(true);
+    assert!(
+        schemas.contains(r#"(value) => !value.includes("a\"b\\c"),"#),
+        "{schemas}"
+    );
+    assert!(
+        schemas.contains(r#"{ message: "must not contain a\"b\\c" },"#),
+        "{schemas}"
+    );
+}
+
+#[test]
+fn only_recursive_schemas_are_annotated() {
+    let schemas = generate(true);
+    assert!(
+        schemas.contains(r#"import { type Category } from "./types";"#),
+        "{schemas}"
+    );
+    assert!(schemas.contains("export const CategorySchema: z.ZodType<Category> = z.object({"));
+    assert!(schemas.contains("export const OwnerSchema = z.object("));
+    assert!(schemas.contains("export const SignupParamsSchema = z.object({"));
+    assert!(schemas.contains("export const CategoryArraySchema = z.array("));
+    assert!(!schemas.contains("z.ZodType ="), "{schemas}");
+
+    let schemas = generate(false);
+    assert!(schemas.contains(r#"import { type Category } from "../../../bindings/Category";"#));
+}